@@ -30,4 +30,12 @@ fn main() {
         .expect("Failed to generate ClankerAuction bindings")
         .write_to_file("src/abi/clanker_auction.rs")
         .expect("Failed to write ClankerAuction bindings");
+
+    // Generate Rust bindings for Uniswap v4 PoolManager ABI (Swap event only)
+    substreams_ethereum::Abigen::new("UniswapV4PoolManager", "abi/uniswap_v4_pool_manager.json")
+        .expect("Failed to load Uniswap v4 PoolManager ABI")
+        .generate()
+        .expect("Failed to generate Uniswap v4 PoolManager bindings")
+        .write_to_file("src/abi/uniswap_v4_pool_manager.rs")
+        .expect("Failed to write Uniswap v4 PoolManager bindings");
 }
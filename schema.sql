@@ -12,6 +12,9 @@ CREATE TABLE IF NOT EXISTS tokens (
     name TEXT NOT NULL,
     symbol VARCHAR(32) NOT NULL,
     image TEXT,
+    image_scheme VARCHAR(8),
+    image_cid TEXT,
+    image_url TEXT,
     metadata TEXT,
     context TEXT,
     pool_id VARCHAR(66) NOT NULL,
@@ -22,6 +25,92 @@ CREATE TABLE IF NOT EXISTS tokens (
     mev_module VARCHAR(42),
     extensions_supply NUMERIC,
     msg_sender VARCHAR(42) NOT NULL,
+    holder_count BIGINT NOT NULL DEFAULT 0,
+    total_supply NUMERIC NOT NULL DEFAULT 0,
+    circulating_supply NUMERIC NOT NULL DEFAULT 0,
+    bridged_in NUMERIC NOT NULL DEFAULT 0,
+    bridged_out NUMERIC NOT NULL DEFAULT 0,
+    verified BOOLEAN NOT NULL DEFAULT FALSE,
+    verified_at_block BIGINT,
+    image_updated_at BIGINT,
+    metadata_updated_at BIGINT,
+    description TEXT,
+    website TEXT,
+    x_url TEXT,
+    x_handle VARCHAR(64),
+    telegram TEXT,
+    farcaster TEXT,
+    discord TEXT,
+    github TEXT,
+    audit_urls TEXT,
+    metadata_extra TEXT,
+    metadata_parse_error TEXT,
+    interface TEXT,
+    platform TEXT,
+    message_id TEXT,
+    user_id TEXT,
+    origin VARCHAR(16),
+    context_parse_error TEXT,
+    fid BIGINT,
+    cast_hash VARCHAR(42),
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Launch counts per origin (scope 'origin') and per interface (scope 'interface',
+-- names capped at 64 characters)
+CREATE TABLE IF NOT EXISTS interface_launches (
+    id VARCHAR(200) PRIMARY KEY,
+    scope VARCHAR(16) NOT NULL,
+    name TEXT NOT NULL,
+    launches BIGINT NOT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Farcaster launches linked to the cast that requested them
+CREATE TABLE IF NOT EXISTS token_casts (
+    token_address VARCHAR(42) PRIMARY KEY,
+    fid BIGINT NOT NULL,
+    cast_hash VARCHAR(42) NOT NULL,
+    interface TEXT,
+    block_number BIGINT NOT NULL,
+    block_timestamp BIGINT NOT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Tokens launched per Farcaster FID
+CREATE TABLE IF NOT EXISTS fid_launches (
+    fid BIGINT PRIMARY KEY,
+    launches BIGINT NOT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Launches reusing an older token's normalized symbol or name
+CREATE TABLE IF NOT EXISTS impersonation_flags (
+    id VARCHAR(128) PRIMARY KEY,
+    tx_hash VARCHAR(66) NOT NULL,
+    block_number BIGINT NOT NULL,
+    block_timestamp BIGINT NOT NULL,
+    token_address VARCHAR(42) NOT NULL,
+    matched_field VARCHAR(8) NOT NULL,
+    value TEXT NOT NULL,
+    normalized TEXT NOT NULL,
+    original_token VARCHAR(42) NOT NULL,
+    original_holder_count BIGINT NOT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Creator profiles
+CREATE TABLE IF NOT EXISTS creators (
+    address VARCHAR(42) PRIMARY KEY,
+    tokens_launched BIGINT NOT NULL,
+    current_tokens BIGINT NOT NULL,
+    first_launch_at BIGINT NOT NULL,
+    last_launch_at BIGINT NOT NULL,
+    fees_claimed NUMERIC NOT NULL, -- team fee claims only, locker rewards not included
+    verified_tokens BIGINT NOT NULL,
+    airdrops_run BIGINT NOT NULL,
+    tokens_trading BIGINT NOT NULL,
+    last_trade_at BIGINT NOT NULL,
     created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
 );
 
@@ -61,6 +150,22 @@ CREATE TABLE IF NOT EXISTS metadata_updates (
     token_address VARCHAR(42) NOT NULL,
     update_type VARCHAR(16) NOT NULL,
     new_value TEXT NOT NULL,
+    image_scheme VARCHAR(8),
+    image_cid TEXT,
+    image_url TEXT,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Token admin transfers
+CREATE TABLE IF NOT EXISTS token_admin_updates (
+    id VARCHAR(128) PRIMARY KEY,
+    tx_hash VARCHAR(66) NOT NULL,
+    block_number BIGINT NOT NULL,
+    block_timestamp BIGINT NOT NULL,
+    log_index BIGINT NOT NULL,
+    token_address VARCHAR(42) NOT NULL,
+    old_admin VARCHAR(42) NOT NULL,
+    new_admin VARCHAR(42) NOT NULL,
     created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
 );
 
@@ -87,6 +192,72 @@ CREATE TABLE IF NOT EXISTS transfers (
     from_address VARCHAR(42) NOT NULL,
     to_address VARCHAR(42) NOT NULL,
     amount NUMERIC NOT NULL,
+    transfer_type VARCHAR(16) NOT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Superchain bridge mints (direction 'in') and burns (direction 'out')
+CREATE TABLE IF NOT EXISTS crosschain_transfers (
+    id VARCHAR(128) PRIMARY KEY,
+    tx_hash VARCHAR(66) NOT NULL,
+    block_number BIGINT NOT NULL,
+    block_timestamp BIGINT NOT NULL,
+    log_index BIGINT NOT NULL,
+    token_address VARCHAR(42) NOT NULL,
+    direction VARCHAR(3) NOT NULL,
+    account VARCHAR(42) NOT NULL,
+    amount NUMERIC NOT NULL,
+    sender VARCHAR(42) NOT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+);
+
+-- ERC20Votes delegation changes
+CREATE TABLE IF NOT EXISTS delegations (
+    id VARCHAR(128) PRIMARY KEY,
+    tx_hash VARCHAR(66) NOT NULL,
+    block_number BIGINT NOT NULL,
+    block_timestamp BIGINT NOT NULL,
+    log_index BIGINT NOT NULL,
+    token_address VARCHAR(42) NOT NULL,
+    delegator VARCHAR(42) NOT NULL,
+    from_delegate VARCHAR(42) NOT NULL,
+    to_delegate VARCHAR(42) NOT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Current voting power per token and delegate
+CREATE TABLE IF NOT EXISTS voting_power (
+    id VARCHAR(128) PRIMARY KEY,
+    token_address VARCHAR(42) NOT NULL,
+    delegate VARCHAR(42) NOT NULL,
+    votes NUMERIC NOT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Approvals that are unlimited or go to an unknown spender
+CREATE TABLE IF NOT EXISTS approval_flags (
+    id VARCHAR(128) PRIMARY KEY,
+    tx_hash VARCHAR(66) NOT NULL,
+    block_number BIGINT NOT NULL,
+    block_timestamp BIGINT NOT NULL,
+    log_index BIGINT NOT NULL,
+    token_address VARCHAR(42) NOT NULL,
+    owner VARCHAR(42) NOT NULL,
+    spender VARCHAR(42) NOT NULL,
+    amount NUMERIC NOT NULL,
+    unlimited BOOLEAN NOT NULL,
+    unknown_spender BOOLEAN NOT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Last approved amount per owner and spender (rows removed when an approval is set to zero)
+-- transferFrom spends are not emitted as Approval events, so allowance is an upper bound
+CREATE TABLE IF NOT EXISTS allowances (
+    id VARCHAR(200) PRIMARY KEY,
+    token_address VARCHAR(42) NOT NULL,
+    owner VARCHAR(42) NOT NULL,
+    spender VARCHAR(42) NOT NULL,
+    allowance NUMERIC NOT NULL,
     created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
 );
 
@@ -115,6 +286,7 @@ CREATE TABLE IF NOT EXISTS airdrop_claims (
     log_index BIGINT NOT NULL,
     token VARCHAR(42) NOT NULL,
     user_address VARCHAR(42) NOT NULL,
+    amount NUMERIC NOT NULL,
     total_claimed NUMERIC NOT NULL,
     still_locked NUMERIC NOT NULL,
     created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
@@ -128,16 +300,193 @@ CREATE TABLE IF NOT EXISTS auction_wins (
     block_timestamp BIGINT NOT NULL,
     log_index BIGINT NOT NULL,
     pool_id VARCHAR(66) NOT NULL,
+    token_address VARCHAR(42),
+    paired_token VARCHAR(42),
     winner VARCHAR(42) NOT NULL,
     payment_amount NUMERIC NOT NULL,
     round BIGINT NOT NULL,
     created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
 );
 
+-- Sniper auction rounds (one row per pool and round)
+CREATE TABLE IF NOT EXISTS auctions (
+    id VARCHAR(128) PRIMARY KEY,
+    pool_id VARCHAR(66) NOT NULL,
+    round BIGINT NOT NULL,
+    token_address VARCHAR(42),
+    paired_token VARCHAR(42),
+    gas_peg NUMERIC,
+    auction_block BIGINT,
+    initialized_at_block BIGINT,
+    initialized_at BIGINT,
+    has_winner BOOLEAN NOT NULL DEFAULT FALSE,
+    winner VARCHAR(42),
+    payment_amount NUMERIC,
+    lp_payment NUMERIC,
+    factory_payment NUMERIC,
+    ended BOOLEAN NOT NULL DEFAULT FALSE,
+    ended_at_block BIGINT,
+    ended_at BIGINT,
+    duration BIGINT,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Sniper leaderboard: total paid and auctions won per winner
+CREATE TABLE IF NOT EXISTS auction_winners (
+    id VARCHAR(128) PRIMARY KEY,
+    winner VARCHAR(42) NOT NULL,
+    total_paid NUMERIC NOT NULL DEFAULT 0,
+    auctions_won BIGINT NOT NULL DEFAULT 0,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Auction revenue per pool (scope 'pool') and per token (scope 'token')
+CREATE TABLE IF NOT EXISTS auction_revenue (
+    id VARCHAR(128) PRIMARY KEY,
+    scope VARCHAR(8) NOT NULL,
+    id_value VARCHAR(66) NOT NULL,
+    revenue NUMERIC NOT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Uniswap v4 swaps in Clanker pools
+CREATE TABLE IF NOT EXISTS swaps (
+    id VARCHAR(128) PRIMARY KEY,
+    tx_hash VARCHAR(66) NOT NULL,
+    block_number BIGINT NOT NULL,
+    block_timestamp BIGINT NOT NULL,
+    log_index BIGINT NOT NULL,
+    pool_id VARCHAR(66) NOT NULL,
+    token_address VARCHAR(42) NOT NULL,
+    paired_token VARCHAR(42) NOT NULL,
+    sender VARCHAR(42) NOT NULL,
+    amount0 NUMERIC NOT NULL,
+    amount1 NUMERIC NOT NULL,
+    sqrt_price_x96 NUMERIC NOT NULL,
+    liquidity NUMERIC NOT NULL,
+    tick INT NOT NULL,
+    fee BIGINT NOT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+);
+
+-- OHLCV price candles (price in paired token, raw units)
+CREATE TABLE IF NOT EXISTS candles (
+    id VARCHAR(128) PRIMARY KEY,
+    token_address VARCHAR(42) NOT NULL,
+    interval VARCHAR(4) NOT NULL,
+    bucket_start BIGINT NOT NULL,
+    open NUMERIC NOT NULL,
+    high NUMERIC NOT NULL,
+    low NUMERIC NOT NULL,
+    close NUMERIC NOT NULL,
+    volume NUMERIC NOT NULL,
+    updated_at_block BIGINT NOT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Unclaimed airdrop supply swept by the airdrop admin
+CREATE TABLE IF NOT EXISTS airdrop_admin_claims (
+    id VARCHAR(128) PRIMARY KEY,
+    tx_hash VARCHAR(66) NOT NULL,
+    block_number BIGINT NOT NULL,
+    block_timestamp BIGINT NOT NULL,
+    log_index BIGINT NOT NULL,
+    token VARCHAR(42) NOT NULL,
+    amount NUMERIC NOT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Outstanding airdrop supply per token (supply minus user and admin claims)
+CREATE TABLE IF NOT EXISTS airdrop_supply (
+    id VARCHAR(128) PRIMARY KEY,
+    token VARCHAR(42) NOT NULL,
+    outstanding NUMERIC NOT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Daily airdrop unlock schedule, one row per airdrop and day (day 0 starts when the lockup ends)
+CREATE TABLE IF NOT EXISTS airdrop_unlocks (
+    id VARCHAR(128) PRIMARY KEY,
+    token VARCHAR(42) NOT NULL,
+    airdrop_tx_hash VARCHAR(66) NOT NULL,
+    day BIGINT NOT NULL,
+    unlock_start BIGINT NOT NULL,
+    unlock_end BIGINT NOT NULL,
+    amount NUMERIC NOT NULL,
+    cumulative_amount NUMERIC NOT NULL,
+    supply NUMERIC NOT NULL,
+    lockup_ends_at BIGINT NOT NULL,
+    vesting_ends_at BIGINT NOT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Current holder balances (rows removed when a balance reaches zero)
+CREATE TABLE IF NOT EXISTS token_holders (
+    id VARCHAR(128) PRIMARY KEY,
+    token_address VARCHAR(42) NOT NULL,
+    holder VARCHAR(42) NOT NULL,
+    balance NUMERIC NOT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Extensions, hooks, lockers and MEV modules registered on the factory
+CREATE TABLE IF NOT EXISTS contract_registry (
+    id VARCHAR(128) PRIMARY KEY,
+    kind VARCHAR(16) NOT NULL,
+    contract_address VARCHAR(42) NOT NULL,
+    factory VARCHAR(42) NOT NULL,
+    pool VARCHAR(42),
+    enabled BOOLEAN NOT NULL,
+    updated_at_block BIGINT NOT NULL,
+    updated_at_timestamp BIGINT NOT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Factory governance audit log
+CREATE TABLE IF NOT EXISTS factory_config_changes (
+    id VARCHAR(128) PRIMARY KEY,
+    tx_hash VARCHAR(66) NOT NULL,
+    block_number BIGINT NOT NULL,
+    block_timestamp BIGINT NOT NULL,
+    log_index BIGINT NOT NULL,
+    factory VARCHAR(42) NOT NULL,
+    changed_by VARCHAR(42) NOT NULL,
+    parameter VARCHAR(32) NOT NULL,
+    subject TEXT,
+    old_value TEXT,
+    new_value TEXT NOT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Token-level events rejected per block (not emitted by a Clanker token)
+CREATE TABLE IF NOT EXISTS event_diagnostics (
+    id VARCHAR(128) PRIMARY KEY,
+    block_number BIGINT NOT NULL,
+    rejected_admin_updates BIGINT NOT NULL,
+    rejected_image_updates BIGINT NOT NULL,
+    rejected_metadata_updates BIGINT NOT NULL,
+    rejected_verifications BIGINT NOT NULL,
+    rejected_crosschain_transfers BIGINT NOT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+);
+
 -- Indexes for common queries
 CREATE INDEX IF NOT EXISTS idx_tokens_block ON tokens(block_number);
 CREATE INDEX IF NOT EXISTS idx_tokens_admin ON tokens(admin);
 CREATE INDEX IF NOT EXISTS idx_tokens_symbol ON tokens(symbol);
+CREATE INDEX IF NOT EXISTS idx_tokens_verified ON tokens(verified);
+CREATE INDEX IF NOT EXISTS idx_tokens_x_handle ON tokens(x_handle);
+CREATE INDEX IF NOT EXISTS idx_tokens_image_cid ON tokens(image_cid);
+CREATE INDEX IF NOT EXISTS idx_tokens_origin ON tokens(origin);
+CREATE INDEX IF NOT EXISTS idx_tokens_fid ON tokens(fid);
+
+CREATE INDEX IF NOT EXISTS idx_creators_launched ON creators(tokens_launched DESC);
+CREATE INDEX IF NOT EXISTS idx_creators_fees ON creators(fees_claimed DESC);
+
+CREATE INDEX IF NOT EXISTS idx_impersonation_flags_token ON impersonation_flags(token_address);
+CREATE INDEX IF NOT EXISTS idx_impersonation_flags_original ON impersonation_flags(original_token);
+
+CREATE INDEX IF NOT EXISTS idx_token_casts_fid ON token_casts(fid, block_number);
 
 CREATE INDEX IF NOT EXISTS idx_fee_claims_token ON fee_claims(token);
 CREATE INDEX IF NOT EXISTS idx_fee_claims_recipient ON fee_claims(recipient);
@@ -151,7 +500,20 @@ CREATE INDEX IF NOT EXISTS idx_metadata_block ON metadata_updates(block_number);
 
 CREATE INDEX IF NOT EXISTS idx_verifications_token ON verifications(token_address);
 
+CREATE INDEX IF NOT EXISTS idx_token_admin_updates_token ON token_admin_updates(token_address);
+CREATE INDEX IF NOT EXISTS idx_token_admin_updates_new_admin ON token_admin_updates(new_admin);
+
 CREATE INDEX IF NOT EXISTS idx_transfers_token ON transfers(token_address);
+CREATE INDEX IF NOT EXISTS idx_delegations_token ON delegations(token_address);
+CREATE INDEX IF NOT EXISTS idx_delegations_delegator ON delegations(delegator);
+CREATE INDEX IF NOT EXISTS idx_voting_power_token ON voting_power(token_address, votes DESC);
+
+CREATE INDEX IF NOT EXISTS idx_approval_flags_owner ON approval_flags(owner);
+CREATE INDEX IF NOT EXISTS idx_approval_flags_token ON approval_flags(token_address);
+CREATE INDEX IF NOT EXISTS idx_allowances_owner ON allowances(owner);
+
+CREATE INDEX IF NOT EXISTS idx_crosschain_transfers_token ON crosschain_transfers(token_address);
+CREATE INDEX IF NOT EXISTS idx_transfers_type ON transfers(token_address, transfer_type);
 CREATE INDEX IF NOT EXISTS idx_transfers_from ON transfers(from_address);
 CREATE INDEX IF NOT EXISTS idx_transfers_to ON transfers(to_address);
 CREATE INDEX IF NOT EXISTS idx_transfers_block ON transfers(block_number);
@@ -164,6 +526,33 @@ CREATE INDEX IF NOT EXISTS idx_airdrop_claims_token ON airdrop_claims(token);
 CREATE INDEX IF NOT EXISTS idx_airdrop_claims_user ON airdrop_claims(user_address);
 CREATE INDEX IF NOT EXISTS idx_airdrop_claims_block ON airdrop_claims(block_number);
 
+CREATE INDEX IF NOT EXISTS idx_airdrop_admin_claims_token ON airdrop_admin_claims(token);
+
+CREATE INDEX IF NOT EXISTS idx_airdrop_unlocks_token ON airdrop_unlocks(token, day);
+CREATE INDEX IF NOT EXISTS idx_airdrop_unlocks_start ON airdrop_unlocks(unlock_start);
+
 CREATE INDEX IF NOT EXISTS idx_auction_wins_pool ON auction_wins(pool_id);
+CREATE INDEX IF NOT EXISTS idx_auction_wins_token ON auction_wins(token_address);
 CREATE INDEX IF NOT EXISTS idx_auction_wins_winner ON auction_wins(winner);
 CREATE INDEX IF NOT EXISTS idx_auction_wins_block ON auction_wins(block_number);
+
+CREATE INDEX IF NOT EXISTS idx_auctions_pool ON auctions(pool_id, round);
+CREATE INDEX IF NOT EXISTS idx_auctions_token ON auctions(token_address);
+
+CREATE INDEX IF NOT EXISTS idx_auction_winners_paid ON auction_winners(total_paid DESC);
+CREATE INDEX IF NOT EXISTS idx_auction_revenue_scope ON auction_revenue(scope, revenue DESC);
+
+CREATE INDEX IF NOT EXISTS idx_swaps_pool ON swaps(pool_id);
+CREATE INDEX IF NOT EXISTS idx_swaps_token ON swaps(token_address);
+CREATE INDEX IF NOT EXISTS idx_swaps_sender ON swaps(sender);
+CREATE INDEX IF NOT EXISTS idx_swaps_block ON swaps(block_number);
+
+CREATE INDEX IF NOT EXISTS idx_candles_token_interval ON candles(token_address, interval, bucket_start);
+
+CREATE INDEX IF NOT EXISTS idx_token_holders_token ON token_holders(token_address);
+CREATE INDEX IF NOT EXISTS idx_token_holders_holder ON token_holders(holder);
+
+CREATE INDEX IF NOT EXISTS idx_contract_registry_kind ON contract_registry(kind);
+
+CREATE INDEX IF NOT EXISTS idx_factory_config_changes_parameter ON factory_config_changes(parameter);
+CREATE INDEX IF NOT EXISTS idx_factory_config_changes_block ON factory_config_changes(block_number);
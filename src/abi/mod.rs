@@ -3,3 +3,4 @@ pub mod clanker_factory;
 pub mod clanker_token;
 pub mod clanker_airdrop;
 pub mod clanker_auction;
+pub mod uniswap_v4_pool_manager;
@@ -5,50 +5,292 @@ use abi::clanker_factory::events as factory_events;
 use abi::clanker_token::events as token_events;
 use abi::clanker_airdrop::events as airdrop_events;
 use abi::clanker_auction::events as auction_events;
+use abi::uniswap_v4_pool_manager::events as pool_manager_events;
 use pb::clanker::v1::{
-    AirdropClaimed, AirdropCreated, AuctionWon, ClankerEvents, ExtensionTriggered, FeeClaim,
-    Token, TokenCreated, TokenMetadataUpdate, TokenTransfer, TokenTransfers, TokenVerified,
+    AirdropAdminClaimed, AirdropClaimed, AirdropCreated, AirdropUnlock, AirdropUnlocks, Auction,
+    AuctionEnded, AuctionInitialized, AuctionRewardsTransferred, AuctionWon, Auctions, Candle,
+    Candles, ClankerEvents, ContractRegistration, CreatorProfile, CrosschainTransfer,
+    DelegateChange, DelegateVotesChange, Delegations, EventDiagnostics, ExtensionTriggered,
+    FactoryAdminEvents, FactoryConfigChange, FactoryConfigChanges, FeeClaim, ImageRef,
+    ImpersonationFlag, ImpersonationFlags, Swap, Swaps, Token, TokenAdminUpdate, TokenApproval,
+    TokenApprovals, TokenContext, TokenCreated, TokenEvents, TokenMetadataUpdate, TokenPrice,
+    TokenPrices, TokenSocials, TokenTransfer, TokenTransfers, TokenVerified, TradingChange,
+    TradingChanges,
 };
+use std::collections::{BTreeMap, BTreeSet};
 use std::str::FromStr;
 use substreams::errors::Error;
-use substreams::scalar::BigInt;
-use substreams::store::{StoreAdd, StoreAddBigInt, StoreAddInt64, StoreGet, StoreGetProto, StoreNew, StoreSet, StoreSetProto};
+use substreams::key;
+use substreams::scalar::{BigDecimal, BigInt};
+use substreams::store::{
+    Appender, DeltaBigInt, DeltaInt64, DeltaProto, Deltas, StoreAdd, StoreAddBigInt, StoreAddInt64,
+    StoreAppend, StoreGet, StoreGetArray, StoreGetBigDecimal, StoreGetBigInt, StoreGetInt64,
+    StoreGetProto, StoreGetString, StoreMax, StoreMaxBigDecimal, StoreMaxInt64, StoreMin,
+    StoreMinBigDecimal, StoreMinInt64, StoreNew, StoreSet, StoreSetBigDecimal, StoreSetBigInt,
+    StoreSetIfNotExists, StoreSetIfNotExistsBigDecimal, StoreSetIfNotExistsString, StoreSetProto,
+    StoreSetString,
+};
 use substreams::Hex;
 use substreams_database_change::pb::sf::substreams::sink::database::v1::DatabaseChanges;
-use substreams_database_change::tables::Tables;
+use substreams_database_change::tables::{Row, Tables};
 use substreams_ethereum::pb::eth::v2::Block;
 use substreams_ethereum::Event;
 
-/// Clanker Factory contract address (can be overridden via params)
-const DEFAULT_CLANKER_FACTORY: &str = "e85a59c628f7d27878aceb4bf3b35733630083a9";
+/// Clanker Factory contract address on Base (params: `clanker_factory`)
+const DEFAULT_CLANKER_FACTORY: &[u8] = &hex_literal::hex!("E85A59c628F7d27878ACeB4bf3b35733630083a9");
+
+/// ClankerAirdropV2 contract address on Base (params: `clanker_airdrop`)
+const DEFAULT_CLANKER_AIRDROP: &[u8] = &hex_literal::hex!("f652B3610D75D81871bf96DB50825d9af28391E0");
+
+/// ClankerSniperAuctionV2 contract address on Base (params: `clanker_auction`)
+const DEFAULT_CLANKER_AUCTION: &[u8] = &hex_literal::hex!("ebB25BB797D82CB78E1bc70406b13233c0854413");
+
+/// Uniswap v4 PoolManager contract address on Base (params: `pool_manager`)
+const DEFAULT_POOL_MANAGER: &[u8] = &hex_literal::hex!("498581fF718922c3f8e6A244956aF099B2652b2b");
+
+/// Uniswap Permit2 on Base, a known approval spender (params: `known_spender`)
+const DEFAULT_PERMIT2: &[u8] = &hex_literal::hex!("000000000022D473030F116dDEE9F6B43aC78BA3");
+
+/// Uniswap v4 Universal Router on Base, a known approval spender (params: `known_spender`)
+const DEFAULT_UNIVERSAL_ROUTER: &[u8] = &hex_literal::hex!("6fF5693b99212Da76ad316178A184AB56D299b43");
+
+/// 2^256 - 1, the unlimited ERC20 allowance
+const MAX_UINT256: &str = "115792089237316195423570985008687907853269984665640564039457584007913129639935";
+
+/// Zero address (mint source / burn destination), hex-encoded
+const ZERO_ADDRESS: &str = "0000000000000000000000000000000000000000";
+
+/// Conventional burn address (0x...dEaD), hex-encoded without 0x
+const DEAD_ADDRESS: &str = "000000000000000000000000000000000000dead";
+
+/// 2^192, used to turn a v4 sqrtPriceX96 into a price ratio
+const Q192: &str = "6277101735386680763835789423207666416102355444464034512896";
+
+/// Candle intervals as (label, length in seconds)
+const CANDLE_INTERVALS: [(&str, u64); 3] = [("1m", 60), ("1h", 3_600), ("1d", 86_400)];
+
+/// Length of one airdrop unlock period in seconds
+const SECONDS_PER_DAY: u64 = 86_400;
+
+/// Length of a 0x-prefixed Farcaster cast hash (20 bytes)
+const CAST_HASH_LEN: usize = 42;
+
+/// Longest interface name counted in store_interface_launch_counts
+const MAX_INTERFACE_NAME_LEN: usize = 64;
+
+/// Longest valid X handle
+const MAX_X_HANDLE_LEN: usize = 15;
+
+/// First path segments of X URLs that are pages, not profiles
+const RESERVED_X_PATHS: &[&str] = &[
+    "i", "intent", "home", "search", "hashtag", "share", "explore", "notifications", "messages", "settings",
+    "compose", "login", "logout", "signup", "tos", "privacy", "about", "download", "jobs", "lists",
+];
 
-/// ClankerAirdropV2 contract address on Base
-const CLANKER_AIRDROP: &[u8] = &hex_literal::hex!("f652B3610D75D81871bf96DB50825d9af28391E0");
+/// Days a token counts as still trading after its last swap
+const STILL_TRADING_DAYS: i64 = 7;
 
-/// ClankerSniperAuctionV2 contract address on Base
-const CLANKER_AUCTION: &[u8] = &hex_literal::hex!("ebB25BB797D82CB78E1bc70406b13233c0854413");
+/// Longest airdrop vesting schedule emitted day by day; the rest unlocks on the last day
+const MAX_UNLOCK_DAYS: u64 = 3_650;
 
-/// Parse the factory address from params
-fn parse_factory_address(params: &str) -> Vec<u8> {
-    let factory_hex = params
+/// Parse the addresses configured for a contract from params
+///
+/// Params are comma-separated `name=address` pairs. A name may be given
+/// several addresses separated by `|` (or be repeated) to track
+/// redeployments side by side. Names that are not set fall back to `defaults`.
+fn parse_contract_addresses(params: &str, name: &str, defaults: &[&[u8]]) -> Vec<Vec<u8>> {
+    let configured: Vec<Vec<u8>> = params
         .split(',')
-        .find_map(|p| {
-            let parts: Vec<&str> = p.split('=').collect();
-            if parts.len() == 2 && parts[0].trim() == "clanker_factory" {
-                Some(parts[1].trim().trim_start_matches("0x"))
+        .filter_map(|p| p.split_once('='))
+        .filter(|(key, _)| key.trim() == name)
+        .flat_map(|(_, value)| value.split('|'))
+        .filter_map(|address| hex::decode(address.trim().trim_start_matches("0x")).ok())
+        .filter(|address| address.len() == 20)
+        .collect();
+
+    if configured.is_empty() {
+        defaults.iter().map(|address| address.to_vec()).collect()
+    } else {
+        configured
+    }
+}
+
+/// Check whether an address is one of the configured contract addresses
+fn is_contract(addresses: &[Vec<u8>], address: &[u8]) -> bool {
+    addresses.iter().any(|a| a.as_slice() == address)
+}
+
+/// Check whether a log comes from a configured contract or one the factory
+/// has registered under `kind`
+///
+/// Contracts stay tracked after being disabled, since airdrops and auctions
+/// that already use them keep emitting events.
+fn is_tracked(
+    addresses: &[Vec<u8>],
+    registry: &StoreGetProto<ContractRegistration>,
+    kind: &str,
+    address: &[u8],
+) -> bool {
+    is_contract(addresses, address)
+        || registry
+            .get_last(&format!("{}:{}", kind, Hex::encode(address)))
+            .is_some()
+}
+
+/// Identify a registry entry by kind and contract, plus the pool for lockers
+/// since they are enabled per pool (hook)
+fn registration_id(registration: &ContractRegistration) -> Vec<&str> {
+    let mut id = vec![registration.kind.as_str(), registration.contract_address.as_str()];
+    if !registration.pool.is_empty() {
+        id.push(registration.pool.as_str());
+    }
+    id
+}
+
+/// Map factory admin events (SetExtension, SetHook, SetLocker, SetMevModule)
+#[substreams::handlers::map]
+pub fn map_factory_admin_events(params: String, block: Block) -> Result<FactoryAdminEvents, Error> {
+    let factory_addresses = parse_contract_addresses(&params, "clanker_factory", &[DEFAULT_CLANKER_FACTORY]);
+    let mut events = FactoryAdminEvents::default();
+
+    let block_number = block.number;
+    let block_timestamp = block.timestamp_seconds();
+
+    for trx in block.transactions() {
+        for (log, _call) in trx.logs_with_calls() {
+            if !is_contract(&factory_addresses, &log.address) {
+                continue;
+            }
+
+            // (kind, contract, pool, enabled) for each registry event
+            let registration = if let Some(event) = factory_events::SetExtension::match_and_decode(log) {
+                Some(("extension", event.extension, Vec::new(), event.enabled))
+            } else if let Some(event) = factory_events::SetHook::match_and_decode(log) {
+                Some(("hook", event.hook, Vec::new(), event.enabled))
+            } else if let Some(event) = factory_events::SetLocker::match_and_decode(log) {
+                Some(("locker", event.locker, event.pool, event.enabled))
+            } else if let Some(event) = factory_events::SetMevModule::match_and_decode(log) {
+                Some(("mev_module", event.mev_module, Vec::new(), event.enabled))
             } else {
                 None
+            };
+
+            // (parameter, subject, old value when the event carries it, new value)
+            let mut config_change = None;
+
+            if let Some((kind, contract, pool, enabled)) = registration {
+                let registration = ContractRegistration {
+                    tx_hash: Hex::encode(&trx.hash),
+                    block_number,
+                    block_timestamp,
+                    log_index: log.index as u64,
+                    factory: Hex::encode(&log.address),
+                    kind: kind.to_string(),
+                    contract_address: Hex::encode(&contract),
+                    pool: Hex::encode(&pool),
+                    enabled,
+                };
+
+                // Registry changes are governance actions too
+                let subject = if registration.pool.is_empty() {
+                    registration.contract_address.clone()
+                } else {
+                    format!("{}/{}", registration.contract_address, registration.pool)
+                };
+                config_change = Some((kind, subject, String::new(), enabled.to_string()));
+
+                events.registrations.push(registration);
+            } else if let Some(event) = factory_events::OwnershipTransferred::match_and_decode(log) {
+                config_change = Some((
+                    "owner",
+                    String::new(),
+                    Hex::encode(&event.previous_owner),
+                    Hex::encode(&event.new_owner),
+                ));
+            } else if let Some(event) = factory_events::SetAdmin::match_and_decode(log) {
+                config_change = Some(("admin", Hex::encode(&event.admin), String::new(), event.enabled.to_string()));
+            } else if let Some(event) = factory_events::SetDeprecated::match_and_decode(log) {
+                config_change = Some(("deprecated", String::new(), String::new(), event.deprecated.to_string()));
+            } else if let Some(event) = factory_events::SetTeamFeeRecipient::match_and_decode(log) {
+                config_change = Some((
+                    "team_fee_recipient",
+                    String::new(),
+                    Hex::encode(&event.old_team_fee_recipient),
+                    Hex::encode(&event.new_team_fee_recipient),
+                ));
             }
-        })
-        .unwrap_or(DEFAULT_CLANKER_FACTORY);
 
-    hex::decode(factory_hex).unwrap_or_else(|_| hex::decode(DEFAULT_CLANKER_FACTORY).unwrap())
+            if let Some((parameter, subject, old_value, new_value)) = config_change {
+                events.config_changes.push(FactoryConfigChange {
+                    tx_hash: Hex::encode(&trx.hash),
+                    block_number,
+                    block_timestamp,
+                    log_index: log.index as u64,
+                    factory: Hex::encode(&log.address),
+                    changed_by: Hex::encode(&trx.from),
+                    parameter: parameter.to_string(),
+                    subject,
+                    old_value,
+                    new_value,
+                });
+            }
+        }
+    }
+
+    Ok(events)
+}
+
+/// Store the contract registry (keyed {kind}:{address}, lockers also under {kind}:{address}:{pool})
+#[substreams::handlers::store]
+pub fn store_contract_registry(events: FactoryAdminEvents, store: StoreSetProto<ContractRegistration>) {
+    for registration in events.registrations {
+        let key = registration_id(&registration).join(":");
+        store.set(0, &key, &registration);
+        // Pool-independent entry so lockers can be looked up by address alone
+        if !registration.pool.is_empty() {
+            store.set(0, &format!("{}:{}", registration.kind, registration.contract_address), &registration);
+        }
+    }
+}
+
+/// Store the latest value of each factory setting (keyed config:{factory}:{parameter}:{subject})
+#[substreams::handlers::store]
+pub fn store_factory_config(events: FactoryAdminEvents, store: StoreSetProto<FactoryConfigChange>) {
+    for change in events.config_changes {
+        let key = format!("config:{}:{}:{}", change.factory, change.parameter, change.subject);
+        store.set(0, &key, &change);
+    }
+}
+
+/// Map factory governance changes with their previous values
+#[substreams::handlers::map]
+pub fn map_factory_config_changes(
+    config: Deltas<DeltaProto<FactoryConfigChange>>,
+) -> Result<FactoryConfigChanges, Error> {
+    let mut changes = FactoryConfigChanges::default();
+
+    for delta in &config.deltas {
+        let mut change = delta.new_value.clone();
+        // Events that don't carry the old value take it from the previous setting
+        if change.old_value.is_empty() {
+            change.old_value = delta.old_value.new_value.clone();
+        }
+        changes.changes.push(change);
+    }
+
+    Ok(changes)
 }
 
 /// Map Clanker factory events (TokenCreated, FeeClaims, etc.)
 #[substreams::handlers::map]
-pub fn map_clanker_events(params: String, block: Block) -> Result<ClankerEvents, Error> {
-    let factory_address = parse_factory_address(&params);
+pub fn map_clanker_events(
+    params: String,
+    block: Block,
+    registry: StoreGetProto<ContractRegistration>,
+) -> Result<ClankerEvents, Error> {
+    let factory_addresses = parse_contract_addresses(&params, "clanker_factory", &[DEFAULT_CLANKER_FACTORY]);
+    let airdrop_addresses = parse_contract_addresses(&params, "clanker_airdrop", &[DEFAULT_CLANKER_AIRDROP]);
+    let auction_addresses = parse_contract_addresses(&params, "clanker_auction", &[DEFAULT_CLANKER_AUCTION]);
     let mut events = ClankerEvents::default();
 
     let block_number = block.number;
@@ -57,7 +299,7 @@ pub fn map_clanker_events(params: String, block: Block) -> Result<ClankerEvents,
     for trx in block.transactions() {
         for (log, _call) in trx.logs_with_calls() {
             // Check if log is from factory contract
-            let is_factory = log.address == factory_address.as_slice();
+            let is_factory = is_contract(&factory_addresses, &log.address);
 
             // Process factory events
             if is_factory {
@@ -81,6 +323,9 @@ pub fn map_clanker_events(params: String, block: Block) -> Result<ClankerEvents,
                         token_image: event.token_image.clone(),
                         token_metadata: event.token_metadata.clone(),
                         token_context: event.token_context.clone(),
+                        socials: Some(parse_socials(&event.token_metadata)),
+                        context: Some(parse_context(&event.token_context)),
+                        image_ref: Some(normalize_image(&event.token_image)),
                         pool_id: Hex::encode(&event.pool_id),
                         pool_hook: Hex::encode(&event.pool_hook),
                         paired_token: Hex::encode(&event.paired_token),
@@ -120,45 +365,11 @@ pub fn map_clanker_events(params: String, block: Block) -> Result<ClankerEvents,
                 }
             }
 
-            // Token-level events (UpdateImage, UpdateMetadata, Verified)
-            // These come from individual token contracts
-            if let Some(event) = token_events::UpdateImage::match_and_decode(log) {
-                events.metadata_updates.push(TokenMetadataUpdate {
-                    tx_hash: Hex::encode(&trx.hash),
-                    block_number,
-                    block_timestamp,
-                    log_index: log.index as u64,
-                    token_address: Hex::encode(&log.address),
-                    update_type: "image".to_string(),
-                    new_value: event.image.clone(),
-                });
-            }
-
-            if let Some(event) = token_events::UpdateMetadata::match_and_decode(log) {
-                events.metadata_updates.push(TokenMetadataUpdate {
-                    tx_hash: Hex::encode(&trx.hash),
-                    block_number,
-                    block_timestamp,
-                    log_index: log.index as u64,
-                    token_address: Hex::encode(&log.address),
-                    update_type: "metadata".to_string(),
-                    new_value: event.metadata.clone(),
-                });
-            }
-
-            if let Some(event) = token_events::Verified::match_and_decode(log) {
-                events.verifications.push(TokenVerified {
-                    tx_hash: Hex::encode(&trx.hash),
-                    block_number,
-                    block_timestamp,
-                    log_index: log.index as u64,
-                    token_address: Hex::encode(&event.token),
-                    admin: Hex::encode(&event.admin),
-                });
-            }
-
-            // Airdrop events (from ClankerAirdropV2)
-            if log.address == CLANKER_AIRDROP {
+            // Airdrop events (from ClankerAirdropV2 or an extension enabled on the factory)
+            let is_airdrop_event = airdrop_events::AirdropCreated::match_log(log)
+                || airdrop_events::AirdropClaimed::match_log(log)
+                || airdrop_events::AirdropAdminClaimed::match_log(log);
+            if is_airdrop_event && is_tracked(&airdrop_addresses, &registry, "extension", &log.address) {
                 if let Some(event) = airdrop_events::AirdropCreated::match_and_decode(log) {
                     events.airdrop_created.push(AirdropCreated {
                         tx_hash: Hex::encode(&trx.hash),
@@ -184,12 +395,28 @@ pub fn map_clanker_events(params: String, block: Block) -> Result<ClankerEvents,
                         user: Hex::encode(&event.user),
                         total_claimed: event.total_user_amount_claimed.to_string(),
                         still_locked: event.user_amount_still_locked.to_string(),
+                        ..Default::default()
+                    });
+                }
+
+                if let Some(event) = airdrop_events::AirdropAdminClaimed::match_and_decode(log) {
+                    events.airdrop_admin_claimed.push(AirdropAdminClaimed {
+                        tx_hash: Hex::encode(&trx.hash),
+                        block_number,
+                        block_timestamp,
+                        log_index: log.index as u64,
+                        token: Hex::encode(&event.token),
+                        amount: event.amount.to_string(),
                     });
                 }
             }
 
-            // MEV Auction events (from ClankerSniperAuctionV2)
-            if log.address == CLANKER_AUCTION {
+            // MEV Auction events (from ClankerSniperAuctionV2 or an MEV module enabled on the factory)
+            let is_auction_event = auction_events::AuctionWon::match_log(log)
+                || auction_events::AuctionInitialized::match_log(log)
+                || auction_events::AuctionEnded::match_log(log)
+                || auction_events::AuctionRewardsTransferred::match_log(log);
+            if is_auction_event && is_tracked(&auction_addresses, &registry, "mev_module", &log.address) {
                 if let Some(event) = auction_events::AuctionWon::match_and_decode(log) {
                     events.auction_won.push(AuctionWon {
                         tx_hash: Hex::encode(&trx.hash),
@@ -200,6 +427,42 @@ pub fn map_clanker_events(params: String, block: Block) -> Result<ClankerEvents,
                         winner: Hex::encode(&event.payee),
                         payment_amount: event.payment_amount.to_string(),
                         round: event.round.to_u64(),
+                        ..Default::default()
+                    });
+                }
+
+                if let Some(event) = auction_events::AuctionInitialized::match_and_decode(log) {
+                    events.auction_initialized.push(AuctionInitialized {
+                        tx_hash: Hex::encode(&trx.hash),
+                        block_number,
+                        block_timestamp,
+                        log_index: log.index as u64,
+                        pool_id: Hex::encode(&event.pool_id),
+                        gas_peg: event.gas_peg.to_string(),
+                        auction_block: event.auction_block.to_u64(),
+                        round: event.round.to_u64(),
+                    });
+                }
+
+                if let Some(event) = auction_events::AuctionEnded::match_and_decode(log) {
+                    events.auction_ended.push(AuctionEnded {
+                        tx_hash: Hex::encode(&trx.hash),
+                        block_number,
+                        block_timestamp,
+                        log_index: log.index as u64,
+                        pool_id: Hex::encode(&event.pool_id),
+                    });
+                }
+
+                if let Some(event) = auction_events::AuctionRewardsTransferred::match_and_decode(log) {
+                    events.auction_rewards.push(AuctionRewardsTransferred {
+                        tx_hash: Hex::encode(&trx.hash),
+                        block_number,
+                        block_timestamp,
+                        log_index: log.index as u64,
+                        pool_id: Hex::encode(&event.pool_id),
+                        lp_payment: event.lp_payment.to_string(),
+                        factory_payment: event.factory_payment.to_string(),
                     });
                 }
             }
@@ -209,36 +472,74 @@ pub fn map_clanker_events(params: String, block: Block) -> Result<ClankerEvents,
     Ok(events)
 }
 
-/// Store tokens in a registry for lookups
+/// Build the stored token record from its creation event
+fn token_record(token_created: &TokenCreated) -> Token {
+    Token {
+        address: token_created.token_address.clone(),
+        name: token_created.token_name.clone(),
+        symbol: token_created.token_symbol.clone(),
+        admin: token_created.token_admin.clone(),
+        image: token_created.token_image.clone(),
+        pool_id: token_created.pool_id.clone(),
+        paired_token: token_created.paired_token.clone(),
+        created_at_block: token_created.block_number,
+        created_at_timestamp: token_created.block_timestamp,
+        metadata: token_created.token_metadata.clone(),
+        socials: token_created.socials.clone(),
+        context: token_created.context.clone(),
+        image_ref: token_created.image_ref.clone(),
+        ..Default::default()
+    }
+}
+
+/// Current state of a token: its creation record with the latest field updates applied
+fn current_token(registry: &StoreGetProto<Token>, fields: &StoreGetString, address: &str) -> Option<Token> {
+    let mut token = registry.get_last(&format!("token:{}", address))?;
+    let field = |name: &str| fields.get_last(&format!("{}:{}", name, address));
+
+    if let Some(admin) = field("admin") {
+        token.admin = admin;
+    }
+    if let Some(image) = field("image") {
+        token.image_ref = Some(normalize_image(&image));
+        token.image = image;
+    }
+    if let Some(metadata) = field("metadata") {
+        token.socials = Some(parse_socials(&metadata));
+        token.metadata = metadata;
+    }
+    if let Some(timestamp) = field("image_updated_at").and_then(|v| v.parse().ok()) {
+        token.image_updated_at = timestamp;
+    }
+    if let Some(timestamp) = field("metadata_updated_at").and_then(|v| v.parse().ok()) {
+        token.metadata_updated_at = timestamp;
+    }
+    if let Some(block_number) = field("verified_at_block").and_then(|v| v.parse().ok()) {
+        token.verified = true;
+        token.verified_at_block = block_number;
+    }
+
+    Some(token)
+}
+
+/// Store tokens as created, used to filter token-level events
 #[substreams::handlers::store]
-pub fn store_tokens(events: ClankerEvents, store: StoreSetProto<Token>) {
-    for token_created in events.token_created {
+pub fn store_token_registry(events: ClankerEvents, store: StoreSetProto<Token>) {
+    for token_created in &events.token_created {
         let key = format!("token:{}", token_created.token_address);
-        store.set(
-            0,
-            &key,
-            &Token {
-                address: token_created.token_address.clone(),
-                name: token_created.token_name,
-                symbol: token_created.token_symbol,
-                admin: token_created.token_admin,
-                image: token_created.token_image,
-                pool_id: token_created.pool_id,
-                paired_token: token_created.paired_token,
-                created_at_block: token_created.block_number,
-                created_at_timestamp: token_created.block_timestamp,
-            },
-        );
+        store.set(0, &key, &token_record(token_created));
     }
 }
 
-/// Map ERC20 transfers for Clanker tokens only
+/// Map token-level events (UpdateAdmin, UpdateImage, UpdateMetadata, Verified,
+/// CrosschainMint, CrosschainBurn) for Clanker tokens only
+///
+/// Same-signature events from other contracts are dropped and counted in the
+/// diagnostics.
 #[substreams::handlers::map]
-pub fn map_token_transfers(
-    block: Block,
-    store: StoreGetProto<Token>,
-) -> Result<TokenTransfers, Error> {
-    let mut transfers = TokenTransfers::default();
+pub fn map_token_events(block: Block, registry: StoreGetProto<Token>) -> Result<TokenEvents, Error> {
+    let mut events = TokenEvents::default();
+    let mut diagnostics = EventDiagnostics::default();
 
     let block_number = block.number;
     let block_timestamp = block.timestamp_seconds();
@@ -246,41 +547,691 @@ pub fn map_token_transfers(
     for trx in block.transactions() {
         for (log, _call) in trx.logs_with_calls() {
             // Check if this is a known Clanker token
-            let token_key = format!("token:{}", Hex::encode(&log.address));
-            if store.get_last(&token_key).is_none() {
-                continue;
+            let token_address = Hex::encode(&log.address);
+            let is_clanker_token =
+                || registry.get_last(&format!("token:{}", token_address)).is_some();
+
+            if let Some(event) = token_events::UpdateAdmin::match_and_decode(log) {
+                if !is_clanker_token() {
+                    diagnostics.rejected_admin_updates += 1;
+                    continue;
+                }
+
+                events.admin_updates.push(TokenAdminUpdate {
+                    tx_hash: Hex::encode(&trx.hash),
+                    block_number,
+                    block_timestamp,
+                    log_index: log.index as u64,
+                    token_address: token_address.clone(),
+                    old_admin: Hex::encode(&event.old_admin),
+                    new_admin: Hex::encode(&event.new_admin),
+                });
             }
 
-            // Transfer event
-            if let Some(event) = token_events::Transfer::match_and_decode(log) {
-                transfers.transfers.push(TokenTransfer {
+            if let Some(event) = token_events::UpdateImage::match_and_decode(log) {
+                if !is_clanker_token() {
+                    diagnostics.rejected_image_updates += 1;
+                    continue;
+                }
+
+                events.metadata_updates.push(TokenMetadataUpdate {
                     tx_hash: Hex::encode(&trx.hash),
                     block_number,
                     block_timestamp,
                     log_index: log.index as u64,
-                    token_address: Hex::encode(&log.address),
-                    from: Hex::encode(&event.from),
-                    to: Hex::encode(&event.to),
-                    amount: event.value.to_string(),
+                    token_address: token_address.clone(),
+                    update_type: "image".to_string(),
+                    new_value: event.image.clone(),
+                    socials: None,
+                    image_ref: Some(normalize_image(&event.image)),
+                });
+            }
+
+            if let Some(event) = token_events::UpdateMetadata::match_and_decode(log) {
+                if !is_clanker_token() {
+                    diagnostics.rejected_metadata_updates += 1;
+                    continue;
+                }
+
+                events.metadata_updates.push(TokenMetadataUpdate {
+                    tx_hash: Hex::encode(&trx.hash),
+                    block_number,
+                    block_timestamp,
+                    log_index: log.index as u64,
+                    token_address: token_address.clone(),
+                    update_type: "metadata".to_string(),
+                    new_value: event.metadata.clone(),
+                    socials: Some(parse_socials(&event.metadata)),
+                    image_ref: None,
+                });
+            }
+
+            if let Some(event) = token_events::Verified::match_and_decode(log) {
+                if !is_clanker_token() {
+                    diagnostics.rejected_verifications += 1;
+                    continue;
+                }
+
+                events.verifications.push(TokenVerified {
+                    tx_hash: Hex::encode(&trx.hash),
+                    block_number,
+                    block_timestamp,
+                    log_index: log.index as u64,
+                    token_address: Hex::encode(&event.token),
+                    admin: Hex::encode(&event.admin),
+                });
+            }
+
+            // ERC-7802 superchain bridge mints and burns
+            if let Some(event) = token_events::CrosschainMint::match_and_decode(log) {
+                if !is_clanker_token() {
+                    diagnostics.rejected_crosschain_transfers += 1;
+                    continue;
+                }
+
+                events.crosschain_transfers.push(CrosschainTransfer {
+                    tx_hash: Hex::encode(&trx.hash),
+                    block_number,
+                    block_timestamp,
+                    log_index: log.index as u64,
+                    token_address: token_address.clone(),
+                    direction: "in".to_string(),
+                    account: Hex::encode(&event.to),
+                    amount: event.amount.to_string(),
+                    sender: Hex::encode(&event.sender),
+                });
+            }
+
+            if let Some(event) = token_events::CrosschainBurn::match_and_decode(log) {
+                if !is_clanker_token() {
+                    diagnostics.rejected_crosschain_transfers += 1;
+                    continue;
+                }
+
+                events.crosschain_transfers.push(CrosschainTransfer {
+                    tx_hash: Hex::encode(&trx.hash),
+                    block_number,
+                    block_timestamp,
+                    log_index: log.index as u64,
+                    token_address: token_address.clone(),
+                    direction: "out".to_string(),
+                    account: Hex::encode(&event.from),
+                    amount: event.amount.to_string(),
+                    sender: Hex::encode(&event.sender),
                 });
             }
         }
     }
 
-    Ok(transfers)
+    diagnostics.block_number = block_number;
+    events.diagnostics = Some(diagnostics);
+
+    Ok(events)
 }
 
-/// Output to database sink
-#[substreams::handlers::map]
-pub fn db_out(
+/// Store the latest value of each mutable token field (keyed {field}:{token})
+#[substreams::handlers::store]
+pub fn store_token_fields(token_events: TokenEvents, store: StoreSetString) {
+    for update in &token_events.admin_updates {
+        store.set(0, &format!("admin:{}", update.token_address), &update.new_admin);
+    }
+
+    for update in &token_events.metadata_updates {
+        let field = update.update_type.as_str();
+        store.set(0, &format!("{}:{}", field, update.token_address), &update.new_value);
+        store.set(
+            0,
+            &format!("{}_updated_at:{}", field, update.token_address),
+            &update.block_timestamp.to_string(),
+        );
+    }
+
+    for verification in &token_events.verifications {
+        let key = format!("verified_at_block:{}", verification.token_address);
+        store.set(0, &key, &verification.block_number.to_string());
+    }
+}
+
+/// Store the current state of each token (creation record plus later updates)
+#[substreams::handlers::store]
+pub fn store_tokens(
     events: ClankerEvents,
-    transfers: TokenTransfers,
-) -> Result<DatabaseChanges, Error> {
-    let mut tables = Tables::new();
+    token_events: TokenEvents,
+    registry: StoreGetProto<Token>,
+    fields: StoreGetString,
+    store: StoreSetProto<Token>,
+) {
+    let mut touched = BTreeSet::new();
+    touched.extend(events.token_created.iter().map(|t| t.token_address.clone()));
+    touched.extend(token_events.admin_updates.iter().map(|u| u.token_address.clone()));
+    touched.extend(token_events.metadata_updates.iter().map(|u| u.token_address.clone()));
+    touched.extend(token_events.verifications.iter().map(|v| v.token_address.clone()));
 
-    // Insert token creations
-    for token in &events.token_created {
-        tables
+    for address in touched {
+        if let Some(token) = current_token(&registry, &fields, &address) {
+            store.set(0, &format!("token:{}", address), &token);
+        }
+    }
+}
+
+/// Store tokens keyed by pool id for pool-keyed lookups (swaps, auctions)
+#[substreams::handlers::store]
+pub fn store_pools(events: ClankerEvents, store: StoreSetProto<Token>) {
+    for token_created in &events.token_created {
+        let key = format!("pool:{}", token_created.pool_id);
+        store.set(0, &key, &token_record(token_created));
+    }
+}
+
+/// Resolve pool-keyed events (auction wins) to their Clanker token
+#[substreams::handlers::map]
+pub fn map_enriched_events(
+    events: ClankerEvents,
+    pools: StoreGetProto<Token>,
+    user_claims: StoreGetBigInt,
+) -> Result<ClankerEvents, Error> {
+    let mut events = events;
+
+    for auction in events.auction_won.iter_mut() {
+        if let Some(token) = pools.get_last(&format!("pool:{}", auction.pool_id)) {
+            auction.token_address = token.address;
+            auction.paired_token = token.paired_token;
+        }
+    }
+
+    // total_claimed is cumulative per user; the claimed amount is the increase
+    // over the user's previous claim (earlier claims in this block included)
+    let mut claimed_in_block: BTreeMap<String, BigInt> = BTreeMap::new();
+    for claim in events.airdrop_claimed.iter_mut() {
+        let key = format!("claimed:{}:{}", claim.token, claim.user);
+        let previous = claimed_in_block
+            .get(&key)
+            .cloned()
+            .or_else(|| user_claims.get_first(&key))
+            .unwrap_or_else(BigInt::zero);
+        let total = BigInt::from_str(&claim.total_claimed).unwrap_or_else(|_| previous.clone());
+
+        let amount = total.clone() - previous;
+        let amount = if amount < BigInt::zero() { BigInt::zero() } else { amount };
+        claim.amount = amount.to_string();
+        claimed_in_block.insert(key, total);
+    }
+
+    Ok(events)
+}
+
+/// Map ERC20 transfers for Clanker tokens only
+#[substreams::handlers::map]
+pub fn map_token_transfers(
+    block: Block,
+    store: StoreGetProto<Token>,
+) -> Result<TokenTransfers, Error> {
+    let mut transfers = TokenTransfers::default();
+
+    let block_number = block.number;
+    let block_timestamp = block.timestamp_seconds();
+
+    for trx in block.transactions() {
+        for (log, _call) in trx.logs_with_calls() {
+            // Check if this is a known Clanker token
+            let token_key = format!("token:{}", Hex::encode(&log.address));
+            if store.get_last(&token_key).is_none() {
+                continue;
+            }
+
+            // Transfer event
+            if let Some(event) = token_events::Transfer::match_and_decode(log) {
+                transfers.transfers.push(TokenTransfer {
+                    tx_hash: Hex::encode(&trx.hash),
+                    block_number,
+                    block_timestamp,
+                    log_index: log.index as u64,
+                    token_address: Hex::encode(&log.address),
+                    from: Hex::encode(&event.from),
+                    to: Hex::encode(&event.to),
+                    amount: event.value.to_string(),
+                    transfer_type: transfer_type(&event.from, &event.to).to_string(),
+                });
+            }
+        }
+    }
+
+    Ok(transfers)
+}
+
+/// Map ERC20Votes delegation events for Clanker tokens only
+#[substreams::handlers::map]
+pub fn map_delegations(block: Block, registry: StoreGetProto<Token>) -> Result<Delegations, Error> {
+    let mut delegations = Delegations::default();
+
+    let block_number = block.number;
+    let block_timestamp = block.timestamp_seconds();
+
+    for trx in block.transactions() {
+        for (log, _call) in trx.logs_with_calls() {
+            let is_delegation_event = token_events::DelegateChanged::match_log(log)
+                || token_events::DelegateVotesChanged::match_log(log);
+            if !is_delegation_event {
+                continue;
+            }
+
+            // Check if this is a known Clanker token
+            let token_address = Hex::encode(&log.address);
+            if registry.get_last(&format!("token:{}", token_address)).is_none() {
+                continue;
+            }
+
+            if let Some(event) = token_events::DelegateChanged::match_and_decode(log) {
+                delegations.delegate_changes.push(DelegateChange {
+                    tx_hash: Hex::encode(&trx.hash),
+                    block_number,
+                    block_timestamp,
+                    log_index: log.index as u64,
+                    token_address: token_address.clone(),
+                    delegator: Hex::encode(&event.delegator),
+                    from_delegate: Hex::encode(&event.from_delegate),
+                    to_delegate: Hex::encode(&event.to_delegate),
+                });
+            }
+
+            if let Some(event) = token_events::DelegateVotesChanged::match_and_decode(log) {
+                delegations.votes_changes.push(DelegateVotesChange {
+                    tx_hash: Hex::encode(&trx.hash),
+                    block_number,
+                    block_timestamp,
+                    log_index: log.index as u64,
+                    token_address,
+                    delegate: Hex::encode(&event.delegate),
+                    previous_votes: event.previous_votes.to_string(),
+                    new_votes: event.new_votes.to_string(),
+                });
+            }
+        }
+    }
+
+    Ok(delegations)
+}
+
+/// Map ERC20 approvals for Clanker tokens only
+///
+/// Approvals are flagged when unlimited or when the spender is neither a
+/// configured known spender nor a contract registered on the factory.
+#[substreams::handlers::map]
+pub fn map_approvals(
+    params: String,
+    block: Block,
+    tokens: StoreGetProto<Token>,
+    contracts: StoreGetProto<ContractRegistration>,
+) -> Result<TokenApprovals, Error> {
+    let mut approvals = TokenApprovals::default();
+
+    let known_spenders = parse_contract_addresses(
+        &params,
+        "known_spender",
+        &[DEFAULT_PERMIT2, DEFAULT_UNIVERSAL_ROUTER],
+    );
+    let unlimited = BigInt::from_str(MAX_UINT256).unwrap_or_else(|_| BigInt::zero());
+
+    let block_number = block.number;
+    let block_timestamp = block.timestamp_seconds();
+
+    for trx in block.transactions() {
+        for (log, _call) in trx.logs_with_calls() {
+            if !token_events::Approval::match_log(log) {
+                continue;
+            }
+
+            // Check if this is a known Clanker token
+            let token_address = Hex::encode(&log.address);
+            if tokens.get_last(&format!("token:{}", token_address)).is_none() {
+                continue;
+            }
+
+            if let Some(event) = token_events::Approval::match_and_decode(log) {
+                let known_spender = ["extension", "hook", "locker", "mev_module"]
+                    .iter()
+                    .any(|kind| is_tracked(&known_spenders, &contracts, kind, &event.spender));
+
+                approvals.approvals.push(TokenApproval {
+                    tx_hash: Hex::encode(&trx.hash),
+                    block_number,
+                    block_timestamp,
+                    log_index: log.index as u64,
+                    token_address,
+                    owner: Hex::encode(&event.owner),
+                    spender: Hex::encode(&event.spender),
+                    amount: event.value.to_string(),
+                    unlimited: event.value >= unlimited,
+                    known_spender,
+                });
+            }
+        }
+    }
+
+    Ok(approvals)
+}
+
+/// Classify a transfer as a mint, a burn, a burn to the dead address or a plain transfer
+fn transfer_type(from: &[u8], to: &[u8]) -> &'static str {
+    if from.iter().all(|b| *b == 0) {
+        "mint"
+    } else if to.iter().all(|b| *b == 0) {
+        "burn"
+    } else if Hex::encode(to) == DEAD_ADDRESS {
+        "dead_burn"
+    } else {
+        "transfer"
+    }
+}
+
+/// Map Uniswap v4 PoolManager swaps for Clanker pools only
+#[substreams::handlers::map]
+pub fn map_pool_swaps(
+    params: String,
+    block: Block,
+    pools: StoreGetProto<Token>,
+) -> Result<Swaps, Error> {
+    let pool_manager_addresses = parse_contract_addresses(&params, "pool_manager", &[DEFAULT_POOL_MANAGER]);
+    let mut swaps = Swaps::default();
+
+    let block_number = block.number;
+    let block_timestamp = block.timestamp_seconds();
+
+    for trx in block.transactions() {
+        for (log, _call) in trx.logs_with_calls() {
+            if !is_contract(&pool_manager_addresses, &log.address) {
+                continue;
+            }
+
+            if let Some(event) = pool_manager_events::Swap::match_and_decode(log) {
+                // Check if this is a pool created by Clanker
+                let pool_id = Hex::encode(&event.id);
+                let token = match pools.get_last(&format!("pool:{}", pool_id)) {
+                    Some(token) => token,
+                    None => continue,
+                };
+
+                swaps.swaps.push(Swap {
+                    tx_hash: Hex::encode(&trx.hash),
+                    block_number,
+                    block_timestamp,
+                    log_index: log.index as u64,
+                    pool_id,
+                    token_address: token.address,
+                    paired_token: token.paired_token,
+                    sender: Hex::encode(&event.sender),
+                    amount0: event.amount0.to_string(),
+                    amount1: event.amount1.to_string(),
+                    sqrt_price_x96: event.sqrt_price_x96.to_string(),
+                    liquidity: event.liquidity.to_string(),
+                    tick: event.tick.to_string().parse::<i32>().unwrap_or(0),
+                    fee: event.fee.to_u64() as u32,
+                });
+            }
+        }
+    }
+
+    Ok(swaps)
+}
+
+/// Map token prices (in the paired token) from launches and swaps
+#[substreams::handlers::map]
+pub fn map_token_prices(events: ClankerEvents, swaps: Swaps) -> Result<TokenPrices, Error> {
+    let mut prices = TokenPrices::default();
+
+    // Launch price from the pool's starting tick
+    for token in &events.token_created {
+        if let Some(price) = tick_to_price(token.starting_tick) {
+            prices.prices.push(TokenPrice {
+                tx_hash: token.tx_hash.clone(),
+                block_number: token.block_number,
+                block_timestamp: token.block_timestamp,
+                log_index: token.log_index,
+                token_address: token.token_address.clone(),
+                paired_token: token.paired_token.clone(),
+                price: price.to_string(),
+                volume: "0".to_string(),
+                source: "launch".to_string(),
+            });
+        }
+    }
+
+    // Execution price from each swap's sqrtPriceX96
+    for swap in &swaps.swaps {
+        let token_is_currency0 = swap.token_address < swap.paired_token;
+        let sqrt_price_x96 = match BigInt::from_str(&swap.sqrt_price_x96) {
+            Ok(value) => value,
+            Err(_) => continue,
+        };
+        let price = match sqrt_price_to_price(&sqrt_price_x96, token_is_currency0) {
+            Some(price) => price,
+            None => continue,
+        };
+
+        let token_amount = if token_is_currency0 { &swap.amount0 } else { &swap.amount1 };
+        let volume = BigInt::from_str(token_amount)
+            .map(|amount| if amount < BigInt::zero() { -amount } else { amount })
+            .unwrap_or_else(|_| BigInt::zero());
+
+        prices.prices.push(TokenPrice {
+            tx_hash: swap.tx_hash.clone(),
+            block_number: swap.block_number,
+            block_timestamp: swap.block_timestamp,
+            log_index: swap.log_index,
+            token_address: swap.token_address.clone(),
+            paired_token: swap.paired_token.clone(),
+            price: price.to_string(),
+            volume: volume.to_string(),
+            source: "swap".to_string(),
+        });
+    }
+
+    Ok(prices)
+}
+
+/// Map candles touched in this block from the candle stores
+#[substreams::handlers::map]
+pub fn map_candles(
+    prices: TokenPrices,
+    opens: StoreGetBigDecimal,
+    highs: StoreGetBigDecimal,
+    lows: StoreGetBigDecimal,
+    closes: StoreGetBigDecimal,
+    volumes: StoreGetBigInt,
+) -> Result<Candles, Error> {
+    let mut candles = Candles::default();
+    let mut touched = BTreeSet::new();
+    let block_number = prices.prices.first().map(|p| p.block_number).unwrap_or_default();
+
+    for price in &prices.prices {
+        for (interval, seconds) in CANDLE_INTERVALS {
+            let bucket_start = price.block_timestamp - price.block_timestamp % seconds;
+            touched.insert((price.token_address.clone(), interval, bucket_start));
+        }
+    }
+
+    for (token_address, interval, bucket_start) in touched {
+        let key = candle_key(interval, &token_address, bucket_start);
+        let open = match opens.get_last(&key) {
+            Some(open) => open,
+            None => continue,
+        };
+
+        candles.candles.push(Candle {
+            token_address,
+            interval: interval.to_string(),
+            bucket_start,
+            open: open.to_string(),
+            high: highs.get_last(&key).unwrap_or_else(|| open.clone()).to_string(),
+            low: lows.get_last(&key).unwrap_or_else(|| open.clone()).to_string(),
+            close: closes.get_last(&key).unwrap_or_else(|| open.clone()).to_string(),
+            volume: volumes.get_last(&key).unwrap_or_else(BigInt::zero).to_string(),
+            updated_at_block: block_number,
+        });
+    }
+
+    Ok(candles)
+}
+
+/// Map the daily unlock schedule of airdrops created in this block
+///
+/// Nothing unlocks before the lockup ends; after that the supply vests
+/// linearly until the end of the vesting period.
+#[substreams::handlers::map]
+pub fn map_airdrop_unlocks(events: ClankerEvents) -> Result<AirdropUnlocks, Error> {
+    let mut unlocks = AirdropUnlocks::default();
+
+    for airdrop in &events.airdrop_created {
+        unlocks.unlocks.extend(airdrop_unlocks(airdrop));
+    }
+
+    Ok(unlocks)
+}
+
+/// Daily unlock schedule of one airdrop
+fn airdrop_unlocks(airdrop: &AirdropCreated) -> Vec<AirdropUnlock> {
+    let mut unlocks = Vec::new();
+
+    let supply = match BigInt::from_str(&airdrop.supply) {
+        Ok(supply) => supply,
+        Err(_) => return unlocks,
+    };
+    let lockup_ends_at = airdrop.block_timestamp.saturating_add(airdrop.lockup_duration);
+    let vesting_ends_at = lockup_ends_at.saturating_add(airdrop.vesting_duration);
+    let vesting = vesting_ends_at - lockup_ends_at;
+
+    // Without vesting the whole supply unlocks when the lockup ends
+    let days = if vesting == 0 {
+        1
+    } else {
+        vesting.div_ceil(SECONDS_PER_DAY).min(MAX_UNLOCK_DAYS)
+    };
+
+    let mut unlocked = BigInt::zero();
+    for day in 0..days {
+        let unlock_start = lockup_ends_at + day * SECONDS_PER_DAY;
+        let unlock_end = if day + 1 == days {
+            vesting_ends_at
+        } else {
+            unlock_start + SECONDS_PER_DAY
+        };
+
+        // Vested supply at the end of the day, rounded down like the contract
+        let cumulative = if vesting == 0 {
+            supply.clone()
+        } else {
+            supply.clone() * BigInt::from(unlock_end - lockup_ends_at) / BigInt::from(vesting)
+        };
+
+        unlocks.push(AirdropUnlock {
+            token: airdrop.token.clone(),
+            airdrop_tx_hash: airdrop.tx_hash.clone(),
+            day,
+            unlock_start,
+            unlock_end,
+            amount: (cumulative.clone() - unlocked).to_string(),
+            cumulative_amount: cumulative.to_string(),
+            supply: airdrop.supply.clone(),
+            lockup_ends_at,
+            vesting_ends_at,
+        });
+        unlocked = cumulative;
+    }
+
+    unlocks
+}
+
+/// Store the first token to use each normalized symbol and name
+///
+/// Launches are read in log order, so within a block the earliest launch wins.
+#[substreams::handlers::store]
+pub fn store_token_names(events: ClankerEvents, store: StoreSetIfNotExistsString) {
+    for token in &events.token_created {
+        for (field, value) in [("symbol", &token.token_symbol), ("name", &token.token_name)] {
+            let normalized = normalize_name(value);
+            if !normalized.is_empty() {
+                store.set_if_not_exists(0, &format!("{}:{}", field, normalized), &token.token_address);
+            }
+        }
+    }
+}
+
+/// Map launches whose normalized symbol or name collides with an older token
+#[substreams::handlers::map]
+pub fn map_impersonation_flags(
+    events: ClankerEvents,
+    names: StoreGetString,
+    holder_counts: StoreGetInt64,
+) -> Result<ImpersonationFlags, Error> {
+    let mut flags = ImpersonationFlags::default();
+
+    for token in &events.token_created {
+        for (field, value) in [("symbol", &token.token_symbol), ("name", &token.token_name)] {
+            let normalized = normalize_name(value);
+            if normalized.is_empty() {
+                continue;
+            }
+
+            let original = match names.get_last(&format!("{}:{}", field, normalized)) {
+                Some(original) if original != token.token_address => original,
+                _ => continue,
+            };
+
+            flags.flags.push(ImpersonationFlag {
+                tx_hash: token.tx_hash.clone(),
+                block_number: token.block_number,
+                block_timestamp: token.block_timestamp,
+                token_address: token.token_address.clone(),
+                matched_field: field.to_string(),
+                value: value.clone(),
+                normalized,
+                original_token: original.clone(),
+                original_holder_count: holder_counts
+                    .get_last(&format!("holders:{}", original))
+                    .unwrap_or_default(),
+            });
+        }
+    }
+
+    Ok(flags)
+}
+
+/// Output to database sink
+#[substreams::handlers::map]
+#[allow(clippy::too_many_arguments)]
+pub fn db_out(
+    events: ClankerEvents,
+    admin_events: FactoryAdminEvents,
+    config_changes: FactoryConfigChanges,
+    token_events: TokenEvents,
+    tokens: Deltas<DeltaProto<Token>>,
+    transfers: TokenTransfers,
+    swaps: Swaps,
+    candles: Candles,
+    airdrop_unlocks: AirdropUnlocks,
+    auctions: Auctions,
+    balances: Deltas<DeltaBigInt>,
+    holder_counts: Deltas<DeltaInt64>,
+    airdrop_outstanding: Deltas<DeltaBigInt>,
+    winner_payments: Deltas<DeltaBigInt>,
+    winner_wins: Deltas<DeltaInt64>,
+    auction_revenue: Deltas<DeltaBigInt>,
+    token_supply: Deltas<DeltaBigInt>,
+    bridged_supply: Deltas<DeltaBigInt>,
+    delegations: Delegations,
+    voting_power: Deltas<DeltaBigInt>,
+    approvals: TokenApprovals,
+    allowances: Deltas<DeltaBigInt>,
+    interface_launches: Deltas<DeltaInt64>,
+    fid_launches: Deltas<DeltaInt64>,
+    impersonation_flags: ImpersonationFlags,
+    creator_profiles: Deltas<DeltaProto<CreatorProfile>>,
+) -> Result<DatabaseChanges, Error> {
+    let mut tables = Tables::new();
+
+    // Insert token creations
+    for token in &events.token_created {
+        let row = tables
             .create_row("tokens", &token.token_address)
             .set("tx_hash", &token.tx_hash)
             .set("block_number", token.block_number)
@@ -300,6 +1251,132 @@ pub fn db_out(
             .set("mev_module", &token.mev_module)
             .set("extensions_supply", &token.extensions_supply)
             .set("msg_sender", &token.msg_sender);
+        if let Some(socials) = &token.socials {
+            set_socials(row, socials);
+        }
+        if let Some(image) = &token.image_ref {
+            set_image_ref(row, image);
+        }
+        if let Some(context) = &token.context {
+            row.set("interface", &context.interface)
+                .set("platform", &context.platform)
+                .set("message_id", &context.message_id)
+                .set("user_id", &context.user_id)
+                .set("origin", &context.origin)
+                .set("context_parse_error", &context.parse_error);
+            if context.fid > 0 {
+                row.set("fid", context.fid).set("cast_hash", &context.cast_hash);
+            }
+        }
+    }
+
+    // Link Farcaster launches to their originating cast
+    for token in &events.token_created {
+        let context = match &token.context {
+            Some(context) if context.fid > 0 => context,
+            _ => continue,
+        };
+        tables
+            .create_row("token_casts", &token.token_address)
+            .set("token_address", &token.token_address)
+            .set("fid", context.fid)
+            .set("cast_hash", &context.cast_hash)
+            .set("interface", &context.interface)
+            .set("block_number", token.block_number)
+            .set("block_timestamp", token.block_timestamp);
+    }
+
+    // Update tokens whose state changed, including tokens created and updated in
+    // this block (their row was inserted above from the raw creation event)
+    for delta in &tokens.deltas {
+        let token = &delta.new_value;
+        let row = tables
+            .update_row("tokens", &token.address)
+            .set("admin", &token.admin)
+            .set("image", &token.image)
+            .set("metadata", &token.metadata)
+            .set("verified", token.verified);
+        // Timestamps stay NULL until the token is first verified or updated
+        if token.verified {
+            row.set("verified_at_block", token.verified_at_block);
+        }
+        if token.image_updated_at > 0 {
+            row.set("image_updated_at", token.image_updated_at);
+        }
+        if token.metadata_updated_at > 0 {
+            row.set("metadata_updated_at", token.metadata_updated_at);
+        }
+        if let Some(socials) = &token.socials {
+            set_socials(row, socials);
+        }
+        if let Some(image) = &token.image_ref {
+            set_image_ref(row, image);
+        }
+    }
+
+    // Insert impersonation flags (launches reusing an older token's symbol or name)
+    for flag in &impersonation_flags.flags {
+        let pk = format!("{}-{}", flag.token_address, flag.matched_field);
+        tables
+            .create_row("impersonation_flags", &pk)
+            .set("tx_hash", &flag.tx_hash)
+            .set("block_number", flag.block_number)
+            .set("block_timestamp", flag.block_timestamp)
+            .set("token_address", &flag.token_address)
+            .set("matched_field", &flag.matched_field)
+            .set("value", &flag.value)
+            .set("normalized", &flag.normalized)
+            .set("original_token", &flag.original_token)
+            .set("original_holder_count", flag.original_holder_count);
+    }
+
+    // Insert rejected token-level events (same-signature events from non-Clanker contracts)
+    if let Some(diagnostics) = &token_events.diagnostics {
+        let rejected = diagnostics.rejected_admin_updates
+            + diagnostics.rejected_image_updates
+            + diagnostics.rejected_metadata_updates
+            + diagnostics.rejected_verifications
+            + diagnostics.rejected_crosschain_transfers;
+        if rejected > 0 {
+            tables
+                .create_row("event_diagnostics", diagnostics.block_number.to_string())
+                .set("block_number", diagnostics.block_number)
+                .set("rejected_admin_updates", diagnostics.rejected_admin_updates)
+                .set("rejected_image_updates", diagnostics.rejected_image_updates)
+                .set("rejected_metadata_updates", diagnostics.rejected_metadata_updates)
+                .set("rejected_verifications", diagnostics.rejected_verifications)
+                .set("rejected_crosschain_transfers", diagnostics.rejected_crosschain_transfers);
+        }
+    }
+
+    // Insert superchain bridge mints and burns
+    for transfer in &token_events.crosschain_transfers {
+        let pk = format!("{}-{}", transfer.tx_hash, transfer.log_index);
+        tables
+            .create_row("crosschain_transfers", &pk)
+            .set("tx_hash", &transfer.tx_hash)
+            .set("block_number", transfer.block_number)
+            .set("block_timestamp", transfer.block_timestamp)
+            .set("log_index", transfer.log_index)
+            .set("token_address", &transfer.token_address)
+            .set("direction", &transfer.direction)
+            .set("account", &transfer.account)
+            .set("amount", &transfer.amount)
+            .set("sender", &transfer.sender);
+    }
+
+    // Insert token admin updates
+    for update in &token_events.admin_updates {
+        let pk = format!("{}-{}", update.tx_hash, update.log_index);
+        tables
+            .create_row("token_admin_updates", &pk)
+            .set("tx_hash", &update.tx_hash)
+            .set("block_number", update.block_number)
+            .set("block_timestamp", update.block_timestamp)
+            .set("log_index", update.log_index)
+            .set("token_address", &update.token_address)
+            .set("old_admin", &update.old_admin)
+            .set("new_admin", &update.new_admin);
     }
 
     // Insert fee claims
@@ -331,9 +1408,9 @@ pub fn db_out(
     }
 
     // Insert metadata updates
-    for update in &events.metadata_updates {
+    for update in &token_events.metadata_updates {
         let pk = format!("{}-{}", update.tx_hash, update.log_index);
-        tables
+        let row = tables
             .create_row("metadata_updates", &pk)
             .set("tx_hash", &update.tx_hash)
             .set("block_number", update.block_number)
@@ -342,10 +1419,13 @@ pub fn db_out(
             .set("token_address", &update.token_address)
             .set("update_type", &update.update_type)
             .set("new_value", &update.new_value);
+        if let Some(image) = &update.image_ref {
+            set_image_ref(row, image);
+        }
     }
 
     // Insert verifications
-    for v in &events.verifications {
+    for v in &token_events.verifications {
         let pk = format!("{}-{}", v.tx_hash, v.log_index);
         tables
             .create_row("verifications", &pk)
@@ -369,7 +1449,82 @@ pub fn db_out(
             .set("token_address", &transfer.token_address)
             .set("from_address", &transfer.from)
             .set("to_address", &transfer.to)
-            .set("amount", &transfer.amount);
+            .set("amount", &transfer.amount)
+            .set("transfer_type", &transfer.transfer_type);
+    }
+
+    // Insert delegation changes
+    for change in &delegations.delegate_changes {
+        let pk = format!("{}-{}", change.tx_hash, change.log_index);
+        tables
+            .create_row("delegations", &pk)
+            .set("tx_hash", &change.tx_hash)
+            .set("block_number", change.block_number)
+            .set("block_timestamp", change.block_timestamp)
+            .set("log_index", change.log_index)
+            .set("token_address", &change.token_address)
+            .set("delegator", &change.delegator)
+            .set("from_delegate", &change.from_delegate)
+            .set("to_delegate", &change.to_delegate);
+    }
+
+    // Upsert current voting power per token and delegate
+    let mut latest_votes = BTreeMap::new();
+    for delta in &voting_power.deltas {
+        latest_votes.insert(delta.key.clone(), delta.new_value.clone());
+    }
+    for (key, votes) in latest_votes {
+        let token_address = key::segment_at(&key, 1);
+        let delegate = key::segment_at(&key, 2);
+        tables
+            .upsert_row("voting_power", format!("{}-{}", token_address, delegate))
+            .set("token_address", token_address)
+            .set("delegate", delegate)
+            .set("votes", votes.to_string());
+    }
+
+    // Insert flagged approvals (unlimited or to an unknown spender)
+    for approval in &approvals.approvals {
+        let revoked = approval.amount == "0";
+        if revoked || (!approval.unlimited && approval.known_spender) {
+            continue;
+        }
+
+        let pk = format!("{}-{}", approval.tx_hash, approval.log_index);
+        tables
+            .create_row("approval_flags", &pk)
+            .set("tx_hash", &approval.tx_hash)
+            .set("block_number", approval.block_number)
+            .set("block_timestamp", approval.block_timestamp)
+            .set("log_index", approval.log_index)
+            .set("token_address", &approval.token_address)
+            .set("owner", &approval.owner)
+            .set("spender", &approval.spender)
+            .set("amount", &approval.amount)
+            .set("unlimited", approval.unlimited)
+            .set("unknown_spender", !approval.known_spender);
+    }
+
+    // Upsert last approved amounts (rows removed when an approval is set to zero)
+    let mut latest_allowances = BTreeMap::new();
+    for delta in &allowances.deltas {
+        latest_allowances.insert(delta.key.clone(), delta.new_value.clone());
+    }
+    for (key, allowance) in latest_allowances {
+        let token_address = key::segment_at(&key, 1);
+        let owner = key::segment_at(&key, 2);
+        let spender = key::segment_at(&key, 3);
+        let pk = format!("{}-{}-{}", token_address, owner, spender);
+        if allowance > BigInt::zero() {
+            tables
+                .upsert_row("allowances", &pk)
+                .set("token_address", token_address)
+                .set("owner", owner)
+                .set("spender", spender)
+                .set("allowance", allowance.to_string());
+        } else {
+            tables.delete_row("allowances", &pk);
+        }
     }
 
     // Insert airdrop created events
@@ -400,10 +1555,42 @@ pub fn db_out(
             .set("log_index", claim.log_index)
             .set("token", &claim.token)
             .set("user_address", &claim.user)
+            .set("amount", &claim.amount)
             .set("total_claimed", &claim.total_claimed)
             .set("still_locked", &claim.still_locked);
     }
 
+    // Insert admin claims of unclaimed airdrop supply
+    for claim in &events.airdrop_admin_claimed {
+        let pk = format!("{}-{}", claim.tx_hash, claim.log_index);
+        tables
+            .create_row("airdrop_admin_claims", &pk)
+            .set("tx_hash", &claim.tx_hash)
+            .set("block_number", claim.block_number)
+            .set("block_timestamp", claim.block_timestamp)
+            .set("log_index", claim.log_index)
+            .set("token", &claim.token)
+            .set("amount", &claim.amount);
+    }
+
+    // Insert airdrop unlock schedules
+    for unlock in &airdrop_unlocks.unlocks {
+        // A token can get several airdrops, so rows are keyed by the airdrop that created them
+        let pk = format!("{}-{}-{}", unlock.airdrop_tx_hash, unlock.token, unlock.day);
+        tables
+            .create_row("airdrop_unlocks", &pk)
+            .set("token", &unlock.token)
+            .set("airdrop_tx_hash", &unlock.airdrop_tx_hash)
+            .set("day", unlock.day)
+            .set("unlock_start", unlock.unlock_start)
+            .set("unlock_end", unlock.unlock_end)
+            .set("amount", &unlock.amount)
+            .set("cumulative_amount", &unlock.cumulative_amount)
+            .set("supply", &unlock.supply)
+            .set("lockup_ends_at", unlock.lockup_ends_at)
+            .set("vesting_ends_at", unlock.vesting_ends_at);
+    }
+
     // Insert auction wins
     for auction in &events.auction_won {
         let pk = format!("{}-{}", auction.tx_hash, auction.log_index);
@@ -414,11 +1601,250 @@ pub fn db_out(
             .set("block_timestamp", auction.block_timestamp)
             .set("log_index", auction.log_index)
             .set("pool_id", &auction.pool_id)
+            .set("token_address", &auction.token_address)
+            .set("paired_token", &auction.paired_token)
             .set("winner", &auction.winner)
             .set("payment_amount", &auction.payment_amount)
             .set("round", auction.round);
     }
 
+    // Upsert contract registry
+    for registration in &admin_events.registrations {
+        let pk = registration_id(registration).join("-");
+        tables
+            .upsert_row("contract_registry", &pk)
+            .set("kind", &registration.kind)
+            .set("contract_address", &registration.contract_address)
+            .set("factory", &registration.factory)
+            .set("pool", &registration.pool)
+            .set("enabled", registration.enabled)
+            .set("updated_at_block", registration.block_number)
+            .set("updated_at_timestamp", registration.block_timestamp);
+    }
+
+    // Insert factory governance changes
+    for change in &config_changes.changes {
+        let pk = format!("{}-{}", change.tx_hash, change.log_index);
+        tables
+            .create_row("factory_config_changes", &pk)
+            .set("tx_hash", &change.tx_hash)
+            .set("block_number", change.block_number)
+            .set("block_timestamp", change.block_timestamp)
+            .set("log_index", change.log_index)
+            .set("factory", &change.factory)
+            .set("changed_by", &change.changed_by)
+            .set("parameter", &change.parameter)
+            .set("subject", &change.subject)
+            .set("old_value", &change.old_value)
+            .set("new_value", &change.new_value);
+    }
+
+    // Upsert auctions (one row per pool and round, fields fill in as events arrive)
+    for auction in &auctions.auctions {
+        let pk = format!("{}-{}", auction.pool_id, auction.round);
+        let row = tables
+            .upsert_row("auctions", &pk)
+            .set("pool_id", &auction.pool_id)
+            .set("round", auction.round)
+            .set("token_address", &auction.token_address)
+            .set("paired_token", &auction.paired_token)
+            .set("has_winner", auction.has_winner)
+            .set("ended", auction.ended);
+
+        if auction.initialized_at_block > 0 {
+            row.set("gas_peg", &auction.gas_peg)
+                .set("auction_block", auction.auction_block)
+                .set("initialized_at_block", auction.initialized_at_block)
+                .set("initialized_at", auction.initialized_at);
+        }
+        if auction.has_winner {
+            row.set("winner", &auction.winner)
+                .set("payment_amount", &auction.payment_amount);
+        }
+        if !auction.lp_payment.is_empty() {
+            row.set("lp_payment", &auction.lp_payment)
+                .set("factory_payment", &auction.factory_payment);
+        }
+        if auction.ended {
+            row.set("ended_at_block", auction.ended_at_block)
+                .set("ended_at", auction.ended_at)
+                .set("duration", auction.duration);
+        }
+    }
+
+    // Insert swaps
+    for swap in &swaps.swaps {
+        let pk = format!("{}-{}", swap.tx_hash, swap.log_index);
+        tables
+            .create_row("swaps", &pk)
+            .set("tx_hash", &swap.tx_hash)
+            .set("block_number", swap.block_number)
+            .set("block_timestamp", swap.block_timestamp)
+            .set("log_index", swap.log_index)
+            .set("pool_id", &swap.pool_id)
+            .set("token_address", &swap.token_address)
+            .set("paired_token", &swap.paired_token)
+            .set("sender", &swap.sender)
+            .set("amount0", &swap.amount0)
+            .set("amount1", &swap.amount1)
+            .set("sqrt_price_x96", &swap.sqrt_price_x96)
+            .set("liquidity", &swap.liquidity)
+            .set("tick", swap.tick)
+            .set("fee", swap.fee as u64);
+    }
+
+    // Upsert candles
+    for candle in &candles.candles {
+        let pk = format!("{}-{}-{}", candle.token_address, candle.interval, candle.bucket_start);
+        tables
+            .upsert_row("candles", &pk)
+            .set("token_address", &candle.token_address)
+            .set("interval", &candle.interval)
+            .set("bucket_start", candle.bucket_start)
+            .set("open", &candle.open)
+            .set("high", &candle.high)
+            .set("low", &candle.low)
+            .set("close", &candle.close)
+            .set("volume", &candle.volume)
+            .set("updated_at_block", candle.updated_at_block);
+    }
+
+    // Upsert holder balances (last balance per holder in this block)
+    let mut latest_balances = BTreeMap::new();
+    for delta in &balances.deltas {
+        latest_balances.insert(delta.key.clone(), delta.new_value.clone());
+    }
+    for (key, balance) in latest_balances {
+        let token_address = key::segment_at(&key, 1);
+        let holder = key::segment_at(&key, 2);
+        let pk = format!("{}-{}", token_address, holder);
+        if balance > BigInt::zero() {
+            tables
+                .upsert_row("token_holders", &pk)
+                .set("token_address", token_address)
+                .set("holder", holder)
+                .set("balance", &balance.to_string());
+        } else {
+            tables.delete_row("token_holders", &pk);
+        }
+    }
+
+    // Update holder counts on the tokens table
+    let mut latest_holder_counts = BTreeMap::new();
+    for delta in &holder_counts.deltas {
+        latest_holder_counts.insert(delta.key.clone(), delta.new_value);
+    }
+    for (key, count) in latest_holder_counts {
+        tables
+            .update_row("tokens", key::segment_at(&key, 1))
+            .set("holder_count", count);
+    }
+
+    // Upsert outstanding airdrop supply
+    let mut latest_outstanding = BTreeMap::new();
+    for delta in &airdrop_outstanding.deltas {
+        latest_outstanding.insert(delta.key.clone(), delta.new_value.clone());
+    }
+    for (key, outstanding) in latest_outstanding {
+        tables
+            .upsert_row("airdrop_supply", key::segment_at(&key, 1))
+            .set("token", key::segment_at(&key, 1))
+            .set("outstanding", outstanding.to_string());
+    }
+
+    // Update total, circulating and bridged supply on the tokens table
+    let mut latest_supply = BTreeMap::new();
+    for delta in token_supply.deltas.iter().chain(bridged_supply.deltas.iter()) {
+        latest_supply.insert(delta.key.clone(), delta.new_value.clone());
+    }
+    for (key, supply) in latest_supply {
+        tables
+            .update_row("tokens", key::segment_at(&key, 1))
+            .set(key::segment_at(&key, 0), supply.to_string());
+    }
+
+    // Upsert the sniper leaderboard (total paid and auctions won per winner)
+    let mut winners: BTreeMap<String, (Option<BigInt>, Option<i64>)> = BTreeMap::new();
+    for delta in &winner_payments.deltas {
+        let winner = key::segment_at(&delta.key, 1).to_string();
+        winners.entry(winner).or_default().0 = Some(delta.new_value.clone());
+    }
+    for delta in &winner_wins.deltas {
+        let winner = key::segment_at(&delta.key, 1).to_string();
+        winners.entry(winner).or_default().1 = Some(delta.new_value);
+    }
+    for (winner, (total_paid, auctions_won)) in winners {
+        let row = tables.upsert_row("auction_winners", &winner).set("winner", &winner);
+        if let Some(total_paid) = total_paid {
+            row.set("total_paid", total_paid.to_string());
+        }
+        if let Some(auctions_won) = auctions_won {
+            row.set("auctions_won", auctions_won);
+        }
+    }
+
+    // Upsert creator profiles
+    let mut latest_profiles = BTreeMap::new();
+    for delta in &creator_profiles.deltas {
+        latest_profiles.insert(delta.key.clone(), &delta.new_value);
+    }
+    for profile in latest_profiles.into_values() {
+        tables
+            .upsert_row("creators", &profile.address)
+            .set("tokens_launched", profile.tokens_launched)
+            .set("current_tokens", profile.current_tokens)
+            .set("first_launch_at", profile.first_launch_at)
+            .set("last_launch_at", profile.last_launch_at)
+            .set("fees_claimed", &profile.fees_claimed)
+            .set("verified_tokens", profile.verified_tokens)
+            .set("airdrops_run", profile.airdrops_run)
+            .set("tokens_trading", profile.tokens_trading)
+            .set("last_trade_at", profile.last_trade_at);
+    }
+
+    // Upsert launch counts per origin and per interface
+    let mut latest_launches = BTreeMap::new();
+    for delta in &interface_launches.deltas {
+        latest_launches.insert(delta.key.clone(), delta.new_value);
+    }
+    for (key, launches) in latest_launches {
+        let scope = key::segment_at(&key, 1);
+        let name = key::segment_at(&key, 2);
+        tables
+            .upsert_row("interface_launches", format!("{}-{}", scope, name))
+            .set("scope", scope)
+            .set("name", name)
+            .set("launches", launches);
+    }
+
+    // Upsert launch counts per Farcaster FID
+    let mut latest_fid_launches = BTreeMap::new();
+    for delta in &fid_launches.deltas {
+        latest_fid_launches.insert(delta.key.clone(), delta.new_value);
+    }
+    for (key, launches) in latest_fid_launches {
+        let fid = key::segment_at(&key, 1);
+        tables
+            .upsert_row("fid_launches", fid)
+            .set("fid", fid)
+            .set("launches", launches);
+    }
+
+    // Upsert auction revenue per pool and per token
+    let mut latest_revenue = BTreeMap::new();
+    for delta in &auction_revenue.deltas {
+        latest_revenue.insert(delta.key.clone(), delta.new_value.clone());
+    }
+    for (key, revenue) in latest_revenue {
+        let scope = key::segment_at(&key, 1);
+        let id = key::segment_at(&key, 2);
+        tables
+            .upsert_row("auction_revenue", format!("{}-{}", scope, id))
+            .set("scope", scope)
+            .set("id_value", id)
+            .set("revenue", revenue.to_string());
+    }
+
     Ok(tables.to_database_changes())
 }
 
@@ -437,6 +1863,110 @@ pub fn store_token_volume(transfers: TokenTransfers, store: StoreAddBigInt) {
     }
 }
 
+/// Store token balances per holder (accumulates BigInt)
+#[substreams::handlers::store]
+pub fn store_balances(transfers: TokenTransfers, store: StoreAddBigInt) {
+    for transfer in &transfers.transfers {
+        let amount = match BigInt::from_str(&transfer.amount) {
+            Ok(amount) => amount,
+            Err(_) => continue,
+        };
+
+        if transfer.from != ZERO_ADDRESS {
+            let key = format!("balance:{}:{}", transfer.token_address, transfer.from);
+            store.add(0, &key, -amount.clone());
+        }
+        if transfer.to != ZERO_ADDRESS {
+            let key = format!("balance:{}:{}", transfer.token_address, transfer.to);
+            store.add(0, &key, amount);
+        }
+    }
+}
+
+/// Store current voting power per token and delegate
+#[substreams::handlers::store]
+pub fn store_voting_power(delegations: Delegations, store: StoreSetBigInt) {
+    for change in &delegations.votes_changes {
+        if let Ok(votes) = BigInt::from_str(&change.new_votes) {
+            let key = format!("votes:{}:{}", change.token_address, change.delegate);
+            store.set(0, &key, &votes);
+        }
+    }
+}
+
+/// Store the last approved amount per token, owner and spender
+///
+/// Tokens don't emit Approval when transferFrom spends an allowance, so this
+/// is an upper bound on the live allowance.
+#[substreams::handlers::store]
+pub fn store_allowances(approvals: TokenApprovals, store: StoreSetBigInt) {
+    for approval in &approvals.approvals {
+        if let Ok(amount) = BigInt::from_str(&approval.amount) {
+            let key = format!(
+                "allowance:{}:{}:{}",
+                approval.token_address, approval.owner, approval.spender
+            );
+            store.set(0, &key, &amount);
+        }
+    }
+}
+
+/// Store total and circulating supply per token
+///
+/// Mints and burns move the total supply; transfers to or from the dead
+/// address only move the circulating supply.
+#[substreams::handlers::store]
+pub fn store_token_supply(transfers: TokenTransfers, store: StoreAddBigInt) {
+    for transfer in &transfers.transfers {
+        let amount = match BigInt::from_str(&transfer.amount) {
+            Ok(amount) => amount,
+            Err(_) => continue,
+        };
+
+        let total_key = format!("total_supply:{}", transfer.token_address);
+        let circulating_key = format!("circulating_supply:{}", transfer.token_address);
+
+        if transfer.from == ZERO_ADDRESS {
+            store.add(0, &total_key, amount.clone());
+        }
+        if transfer.to == ZERO_ADDRESS {
+            store.add(0, &total_key, -amount.clone());
+        }
+        if transfer.from == ZERO_ADDRESS || transfer.from == DEAD_ADDRESS {
+            store.add(0, &circulating_key, amount.clone());
+        }
+        if transfer.to == ZERO_ADDRESS || transfer.to == DEAD_ADDRESS {
+            store.add(0, &circulating_key, -amount);
+        }
+    }
+}
+
+/// Store supply bridged in and out through the superchain bridge per token
+#[substreams::handlers::store]
+pub fn store_bridged_supply(token_events: TokenEvents, store: StoreAddBigInt) {
+    for transfer in &token_events.crosschain_transfers {
+        if let Ok(amount) = BigInt::from_str(&transfer.amount) {
+            let key = format!("bridged_{}:{}", transfer.direction, transfer.token_address);
+            store.add(0, &key, amount);
+        }
+    }
+}
+
+/// Store holder counts per token (balances crossing zero)
+#[substreams::handlers::store]
+pub fn store_holder_counts(balances: Deltas<DeltaBigInt>, store: StoreAddInt64) {
+    for delta in &balances.deltas {
+        let was_holder = delta.old_value > BigInt::zero();
+        let is_holder = delta.new_value > BigInt::zero();
+        if was_holder == is_holder {
+            continue;
+        }
+
+        let key = format!("holders:{}", key::segment_at(&delta.key, 1));
+        store.add(0, &key, if is_holder { 1 } else { -1 });
+    }
+}
+
 /// Store transfer counts per token
 #[substreams::handlers::store]
 pub fn store_token_transfer_counts(transfers: TokenTransfers, store: StoreAddInt64) {
@@ -446,7 +1976,9 @@ pub fn store_token_transfer_counts(transfers: TokenTransfers, store: StoreAddInt
     }
 }
 
-/// Store total fees claimed per creator (recipient)
+/// Store total team fees claimed per creator (recipient)
+///
+/// Only ClaimTeamFees is counted; locker reward claims are not indexed yet.
 #[substreams::handlers::store]
 pub fn store_creator_fees(events: ClankerEvents, store: StoreAddBigInt) {
     for fee in &events.fee_claims {
@@ -459,11 +1991,276 @@ pub fn store_creator_fees(events: ClankerEvents, store: StoreAddBigInt) {
 
 /// Store token counts per creator
 #[substreams::handlers::store]
-pub fn store_creator_token_counts(events: ClankerEvents, store: StoreAddInt64) {
+pub fn store_creator_token_counts(events: ClankerEvents, token_events: TokenEvents, store: StoreAddInt64) {
     for token in &events.token_created {
         let key = format!("tokens:{}", token.token_admin);
         store.add(0, &key, 1);
     }
+
+    // Move tokens to their new admin when admin rights are handed off
+    for update in &token_events.admin_updates {
+        store.add(0, &format!("tokens:{}", update.old_admin), -1);
+        if update.new_admin != ZERO_ADDRESS {
+            store.add(0, &format!("tokens:{}", update.new_admin), 1);
+        }
+    }
+}
+
+/// Store launch counts per origin and per interface named in the token context
+#[substreams::handlers::store]
+pub fn store_interface_launch_counts(events: ClankerEvents, store: StoreAddInt64) {
+    for token in &events.token_created {
+        let context = token.context.clone().unwrap_or_default();
+        store.add(0, &format!("launches:origin:{}", context.origin), 1);
+        if !context.interface.is_empty() {
+            // Keys are split on ':', which interface names may contain, and the
+            // name is capped since it is part of the interface_launches key
+            let interface: String = context
+                .interface
+                .to_lowercase()
+                .replace(':', "_")
+                .chars()
+                .take(MAX_INTERFACE_NAME_LEN)
+                .collect();
+            store.add(0, &format!("launches:interface:{}", interface), 1);
+        }
+    }
+}
+
+/// Store tokens launched per Farcaster FID
+#[substreams::handlers::store]
+pub fn store_fid_launch_counts(events: ClankerEvents, store: StoreAddInt64) {
+    for token in &events.token_created {
+        if let Some(context) = token.context.as_ref().filter(|c| c.fid > 0) {
+            store.add(0, &format!("launches:{}", context.fid), 1);
+        }
+    }
+}
+
+/// Store the last swap timestamp per token
+#[substreams::handlers::store]
+pub fn store_token_last_swap(swaps: Swaps, store: StoreMaxInt64) {
+    for swap in &swaps.swaps {
+        let key = format!("last_swap:{}", swap.token_address);
+        store.max(0, &key, swap.block_timestamp as i64);
+    }
+}
+
+/// Day number of a unix timestamp
+fn day_of(timestamp: i64) -> i64 {
+    timestamp / SECONDS_PER_DAY as i64
+}
+
+/// Store the current day of the chain, so the first block of each day shows up as a delta
+#[substreams::handlers::store]
+pub fn store_current_day(block: Block, store: StoreMaxInt64) {
+    store.max(0, "day", day_of(block.timestamp_seconds() as i64));
+}
+
+/// Store the tokens whose still-trading window ends on each day (keyed expiry:{day})
+///
+/// A token is appended once for every day it swaps in, under the day its
+/// window would end if it never swapped again.
+#[substreams::handlers::store]
+pub fn store_swap_expiries(swaps: Swaps, last_swaps: StoreGetInt64, store: StoreAppend<String>) {
+    let mut swapped = BTreeMap::new();
+    for swap in &swaps.swaps {
+        swapped.insert(swap.token_address.clone(), swap.block_timestamp as i64);
+    }
+
+    for (token, timestamp) in swapped {
+        let day = day_of(timestamp);
+        let previous_day = last_swaps.get_first(&format!("last_swap:{}", token)).map(day_of);
+        if previous_day != Some(day) {
+            store.append(0, &format!("expiry:{}", day + STILL_TRADING_DAYS), token);
+        }
+    }
+}
+
+/// Map tokens that start or stop trading
+///
+/// A token is still trading while it had a swap in the last STILL_TRADING_DAYS
+/// days. Windows that lapse are picked up on the first block of the day they end.
+#[substreams::handlers::map]
+pub fn map_trading_changes(
+    swaps: Swaps,
+    days: Deltas<DeltaInt64>,
+    expiries: StoreGetArray<String>,
+    last_swaps: StoreGetInt64,
+    registry: StoreGetProto<Token>,
+) -> Result<TradingChanges, Error> {
+    // (token, trading) in the order they are detected
+    let mut updates = Vec::new();
+
+    // Tokens whose window ended stop trading unless they swapped since
+    if let Some(delta) = days.deltas.last() {
+        let first_day = if delta.old_value > 0 { delta.old_value + 1 } else { delta.new_value };
+        for day in first_day..=delta.new_value {
+            for token in expiries.get_last(&format!("expiry:{}", day)).unwrap_or_default() {
+                let last_swap = last_swaps.get_first(&format!("last_swap:{}", token)).unwrap_or_default();
+                if day_of(last_swap) + STILL_TRADING_DAYS == day {
+                    updates.push((token, false));
+                }
+            }
+        }
+    }
+
+    // Tokens swapping for the first time, or again after their window ended, start trading
+    let mut swapped = BTreeSet::new();
+    for swap in &swaps.swaps {
+        if !swapped.insert(swap.token_address.clone()) {
+            continue;
+        }
+        let day = day_of(swap.block_timestamp as i64);
+        let was_trading = last_swaps
+            .get_first(&format!("last_swap:{}", swap.token_address))
+            .is_some_and(|last_swap| day_of(last_swap) + STILL_TRADING_DAYS > day);
+        if !was_trading {
+            updates.push((swap.token_address.clone(), true));
+        }
+    }
+
+    let mut changes = TradingChanges::default();
+    for (token, trading) in updates {
+        if let Some(record) = registry.get_last(&format!("token:{}", token)) {
+            changes.changes.push(TradingChange {
+                token_address: record.address,
+                creator: record.admin,
+                trading,
+            });
+        }
+    }
+
+    Ok(changes)
+}
+
+/// Store creator counters: tokens launched, verified tokens, airdrops run and
+/// tokens still trading
+///
+/// The creator of a token is its admin at launch, so these counts do not move
+/// when admin rights are handed off.
+#[substreams::handlers::store]
+pub fn store_creator_stats(
+    events: ClankerEvents,
+    tokens: Deltas<DeltaProto<Token>>,
+    trading: TradingChanges,
+    registry: StoreGetProto<Token>,
+    store: StoreAddInt64,
+) {
+    for token in &events.token_created {
+        store.add(0, &format!("launches:{}", token.token_admin), 1);
+    }
+
+    for airdrop in &events.airdrop_created {
+        store.add(0, &format!("airdrops:{}", airdrop.admin), 1);
+    }
+
+    // Tokens count once, when they are first verified
+    for delta in &tokens.deltas {
+        if delta.old_value.verified || !delta.new_value.verified {
+            continue;
+        }
+        if let Some(token) = registry.get_last(&format!("token:{}", delta.new_value.address)) {
+            store.add(0, &format!("verified:{}", token.admin), 1);
+        }
+    }
+
+    for change in &trading.changes {
+        let delta = if change.trading { 1 } else { -1 };
+        store.add(0, &format!("trading:{}", change.creator), delta);
+    }
+}
+
+/// Store the first launch timestamp per creator
+#[substreams::handlers::store]
+pub fn store_creator_first_launch(events: ClankerEvents, store: StoreMinInt64) {
+    for token in &events.token_created {
+        let key = format!("first_launch:{}", token.token_admin);
+        store.min(0, &key, token.block_timestamp as i64);
+    }
+}
+
+/// Store the last launch and last trade timestamps per creator
+#[substreams::handlers::store]
+pub fn store_creator_last_activity(
+    events: ClankerEvents,
+    swaps: Swaps,
+    registry: StoreGetProto<Token>,
+    store: StoreMaxInt64,
+) {
+    for token in &events.token_created {
+        let key = format!("last_launch:{}", token.token_admin);
+        store.max(0, &key, token.block_timestamp as i64);
+    }
+
+    for swap in &swaps.swaps {
+        if let Some(token) = registry.get_last(&format!("token:{}", swap.token_address)) {
+            let key = format!("last_trade:{}", token.admin);
+            store.max(0, &key, swap.block_timestamp as i64);
+        }
+    }
+}
+
+/// Store the reputation profile of every creator touched in this block
+#[substreams::handlers::store]
+#[allow(clippy::too_many_arguments)]
+pub fn store_creator_profiles(
+    events: ClankerEvents,
+    token_events: TokenEvents,
+    swaps: Swaps,
+    trading: TradingChanges,
+    registry: StoreGetProto<Token>,
+    stats: StoreGetInt64,
+    first_launch: StoreGetInt64,
+    last_activity: StoreGetInt64,
+    token_counts: StoreGetInt64,
+    fees: StoreGetBigInt,
+    store: StoreSetProto<CreatorProfile>,
+) {
+    let creator = |token: &str| registry.get_last(&format!("token:{}", token)).map(|t| t.admin);
+
+    let mut touched = BTreeSet::new();
+    touched.extend(events.token_created.iter().map(|t| t.token_admin.clone()));
+    touched.extend(events.airdrop_created.iter().map(|a| a.admin.clone()));
+    touched.extend(events.fee_claims.iter().map(|f| f.recipient.clone()));
+    touched.extend(token_events.verifications.iter().filter_map(|v| creator(&v.token_address)));
+    touched.extend(
+        token_events
+            .admin_updates
+            .iter()
+            .flat_map(|u| [u.old_admin.clone(), u.new_admin.clone()]),
+    );
+    touched.extend(swaps.swaps.iter().filter_map(|s| creator(&s.token_address)));
+    touched.extend(trading.changes.iter().map(|c| c.creator.clone()));
+
+    for address in touched {
+        let count = |store: &StoreGetInt64, name: &str| {
+            store.get_last(&format!("{}:{}", name, address)).unwrap_or_default()
+        };
+
+        let profile = CreatorProfile {
+            address: address.clone(),
+            tokens_launched: count(&stats, "launches"),
+            current_tokens: count(&token_counts, "tokens"),
+            first_launch_at: count(&first_launch, "first_launch") as u64,
+            last_launch_at: count(&last_activity, "last_launch") as u64,
+            fees_claimed: fees
+                .get_last(&format!("fees:{}", address))
+                .unwrap_or_else(BigInt::zero)
+                .to_string(),
+            verified_tokens: count(&stats, "verified"),
+            airdrops_run: count(&stats, "airdrops"),
+            tokens_trading: count(&stats, "trading"),
+            last_trade_at: count(&last_activity, "last_trade") as u64,
+        };
+
+        // Fee recipients and admins that never launched or ran an airdrop are not creators
+        if profile.tokens_launched == 0 && profile.airdrops_run == 0 {
+            continue;
+        }
+
+        store.set(0, &format!("creator:{}", address), &profile);
+    }
 }
 
 /// Store airdrop claim counts per token
@@ -475,14 +2272,1157 @@ pub fn store_airdrop_claims_per_token(events: ClankerEvents, store: StoreAddInt6
     }
 }
 
+/// Store the cumulative amount claimed per airdrop recipient
+#[substreams::handlers::store]
+pub fn store_airdrop_user_claims(events: ClankerEvents, store: StoreSetBigInt) {
+    for claim in &events.airdrop_claimed {
+        if let Ok(total) = BigInt::from_str(&claim.total_claimed) {
+            store.set(0, &format!("claimed:{}:{}", claim.token, claim.user), &total);
+        }
+    }
+}
+
+/// Store outstanding airdrop supply per token
+///
+/// Creation supply minus user claims minus admin claims.
+#[substreams::handlers::store]
+pub fn store_airdrop_outstanding(events: ClankerEvents, store: StoreAddBigInt) {
+    for airdrop in &events.airdrop_created {
+        if let Ok(supply) = BigInt::from_str(&airdrop.supply) {
+            store.add(0, &format!("outstanding:{}", airdrop.token), supply);
+        }
+    }
+
+    for claim in &events.airdrop_claimed {
+        if let Ok(amount) = BigInt::from_str(&claim.amount) {
+            store.add(0, &format!("outstanding:{}", claim.token), -amount);
+        }
+    }
+
+    for claim in &events.airdrop_admin_claimed {
+        if let Ok(amount) = BigInt::from_str(&claim.amount) {
+            store.add(0, &format!("outstanding:{}", claim.token), -amount);
+        }
+    }
+}
+
 /// Store airdrop claim volume per token (BigInt)
 #[substreams::handlers::store]
 pub fn store_airdrop_volume_per_token(events: ClankerEvents, store: StoreAddBigInt) {
     for claim in &events.airdrop_claimed {
         let key = format!("airdrop_volume:{}", claim.token);
-        if let Ok(amount) = BigInt::from_str(&claim.total_claimed) {
+        if let Ok(amount) = BigInt::from_str(&claim.amount) {
             store.add(0, &key, amount);
         }
     }
 }
 
+// ============================================================================
+// Price Candles
+// ============================================================================
+
+/// Store the opening price per candle (first price in the bucket wins)
+#[substreams::handlers::store]
+pub fn store_candle_open(prices: TokenPrices, store: StoreSetIfNotExistsBigDecimal) {
+    for price in &prices.prices {
+        if let Ok(value) = BigDecimal::from_str(&price.price) {
+            for key in candle_keys(price) {
+                store.set_if_not_exists(0, &key, &value);
+            }
+        }
+    }
+}
+
+/// Store the highest price per candle
+#[substreams::handlers::store]
+pub fn store_candle_high(prices: TokenPrices, store: StoreMaxBigDecimal) {
+    for price in &prices.prices {
+        if let Ok(value) = BigDecimal::from_str(&price.price) {
+            for key in candle_keys(price) {
+                store.max(0, &key, value.clone());
+            }
+        }
+    }
+}
+
+/// Store the lowest price per candle
+#[substreams::handlers::store]
+pub fn store_candle_low(prices: TokenPrices, store: StoreMinBigDecimal) {
+    for price in &prices.prices {
+        if let Ok(value) = BigDecimal::from_str(&price.price) {
+            for key in candle_keys(price) {
+                store.min(0, &key, value.clone());
+            }
+        }
+    }
+}
+
+/// Store the closing price per candle (last price in the bucket wins)
+#[substreams::handlers::store]
+pub fn store_candle_close(prices: TokenPrices, store: StoreSetBigDecimal) {
+    for price in &prices.prices {
+        if let Ok(value) = BigDecimal::from_str(&price.price) {
+            for key in candle_keys(price) {
+                store.set(0, &key, &value);
+            }
+        }
+    }
+}
+
+/// Store the traded token volume per candle (BigInt)
+#[substreams::handlers::store]
+pub fn store_candle_volume(prices: TokenPrices, store: StoreAddBigInt) {
+    for price in &prices.prices {
+        if let Ok(volume) = BigInt::from_str(&price.volume) {
+            for key in candle_keys(price) {
+                store.add(0, &key, volume.clone());
+            }
+        }
+    }
+}
+
+/// Candle store key for one token, interval and bucket
+fn candle_key(interval: &str, token_address: &str, bucket_start: u64) -> String {
+    format!("candle:{}:{}:{}", interval, token_address, bucket_start)
+}
+
+/// Candle store keys for every interval a price falls into
+fn candle_keys(price: &TokenPrice) -> Vec<String> {
+    CANDLE_INTERVALS
+        .iter()
+        .map(|(interval, seconds)| {
+            let bucket_start = price.block_timestamp - price.block_timestamp % seconds;
+            candle_key(interval, &price.token_address, bucket_start)
+        })
+        .collect()
+}
+
+/// Price of the token in its paired token from a v4 sqrtPriceX96
+///
+/// sqrtPriceX96^2 / 2^192 is currency1 per currency0, so it is inverted
+/// when the Clanker token sorts after its paired token.
+fn sqrt_price_to_price(sqrt_price_x96: &BigInt, token_is_currency0: bool) -> Option<BigDecimal> {
+    if sqrt_price_x96.is_zero() {
+        return None;
+    }
+
+    let squared = sqrt_price_x96.clone() * sqrt_price_x96.clone();
+    let squared = BigDecimal::from_str(&squared.to_string()).ok()?;
+    let q192 = BigDecimal::from_str(Q192).ok()?;
+
+    let price = if token_is_currency0 {
+        squared / q192
+    } else {
+        q192 / squared
+    };
+
+    Some(price.with_prec(30))
+}
+
+/// Price of the token in its paired token from a Clanker starting tick
+///
+/// Clanker emits the tick as if the token were currency0, so 1.0001^tick
+/// is always the launch price in the paired token.
+fn tick_to_price(tick: i32) -> Option<BigDecimal> {
+    let price = 1.0001_f64.powi(tick);
+    if !price.is_finite() || price <= 0.0 {
+        return None;
+    }
+
+    BigDecimal::from_str(&price.to_string()).ok().map(|p| p.with_prec(30))
+}
+
+// ============================================================================
+// Sniper Auctions
+// ============================================================================
+
+/// Store the latest auction round per pool
+#[substreams::handlers::store]
+pub fn store_auction_rounds(events: ClankerEvents, store: StoreMaxInt64) {
+    for auction in &events.auction_initialized {
+        store.max(0, &format!("round:{}", auction.pool_id), auction.round as i64);
+    }
+
+    for auction in &events.auction_won {
+        store.max(0, &format!("round:{}", auction.pool_id), auction.round as i64);
+    }
+}
+
+/// Store the latest value of each auction field, keyed by pool and round
+///
+/// AuctionEnded and AuctionRewardsTransferred carry no round: rewards belong
+/// to the win in the same transaction, an end to the pool's latest round.
+#[substreams::handlers::store]
+pub fn store_auction_fields(events: ClankerEvents, rounds: StoreGetInt64, store: StoreSetString) {
+    for auction in &events.auction_initialized {
+        let id = format!("{}:{}", auction.pool_id, auction.round);
+        store.set(0, &format!("gas_peg:{}", id), &auction.gas_peg);
+        store.set(0, &format!("auction_block:{}", id), &auction.auction_block.to_string());
+        store.set(0, &format!("initialized_at_block:{}", id), &auction.block_number.to_string());
+        store.set(0, &format!("initialized_at:{}", id), &auction.block_timestamp.to_string());
+    }
+
+    for auction in &events.auction_won {
+        let id = format!("{}:{}", auction.pool_id, auction.round);
+        store.set(0, &format!("winner:{}", id), &auction.winner);
+        store.set(0, &format!("payment_amount:{}", id), &auction.payment_amount);
+    }
+
+    for rewards in &events.auction_rewards {
+        let Some(round) = auction_round(&events, &rounds, &rewards.pool_id, &rewards.tx_hash) else {
+            continue;
+        };
+        let id = format!("{}:{}", rewards.pool_id, round);
+        store.set(0, &format!("lp_payment:{}", id), &rewards.lp_payment);
+        store.set(0, &format!("factory_payment:{}", id), &rewards.factory_payment);
+    }
+
+    for ended in &events.auction_ended {
+        let Some(round) = auction_round(&events, &rounds, &ended.pool_id, &ended.tx_hash) else {
+            continue;
+        };
+        let id = format!("{}:{}", ended.pool_id, round);
+        store.set(0, &format!("ended_at_block:{}", id), &ended.block_number.to_string());
+        store.set(0, &format!("ended_at:{}", id), &ended.block_timestamp.to_string());
+    }
+}
+
+/// Map the current state of every auction touched in this block
+#[substreams::handlers::map]
+pub fn map_auctions(
+    events: ClankerEvents,
+    rounds: StoreGetInt64,
+    fields: StoreGetString,
+    pools: StoreGetProto<Token>,
+) -> Result<Auctions, Error> {
+    let mut auctions = Auctions::default();
+
+    let mut touched = BTreeSet::new();
+    touched.extend(events.auction_initialized.iter().map(|a| (a.pool_id.clone(), a.round)));
+    touched.extend(events.auction_won.iter().map(|a| (a.pool_id.clone(), a.round)));
+    for (pool_id, tx_hash) in events
+        .auction_rewards
+        .iter()
+        .map(|r| (&r.pool_id, &r.tx_hash))
+        .chain(events.auction_ended.iter().map(|e| (&e.pool_id, &e.tx_hash)))
+    {
+        if let Some(round) = auction_round(&events, &rounds, pool_id, tx_hash) {
+            touched.insert((pool_id.clone(), round));
+        }
+    }
+
+    for (pool_id, round) in touched {
+        let id = format!("{}:{}", pool_id, round);
+        let field = |name: &str| fields.get_last(&format!("{}:{}", name, id)).unwrap_or_default();
+        let number = |name: &str| field(name).parse::<u64>().unwrap_or_default();
+
+        let mut auction = Auction {
+            pool_id: pool_id.clone(),
+            round,
+            gas_peg: field("gas_peg"),
+            auction_block: number("auction_block"),
+            initialized_at_block: number("initialized_at_block"),
+            initialized_at: number("initialized_at"),
+            winner: field("winner"),
+            payment_amount: field("payment_amount"),
+            lp_payment: field("lp_payment"),
+            factory_payment: field("factory_payment"),
+            ended_at_block: number("ended_at_block"),
+            ended_at: number("ended_at"),
+            ..Default::default()
+        };
+        auction.has_winner = !auction.winner.is_empty();
+        auction.ended = auction.ended_at_block > 0;
+        if auction.ended && auction.initialized_at > 0 {
+            auction.duration = auction.ended_at.saturating_sub(auction.initialized_at);
+        }
+
+        if let Some(token) = pools.get_last(&format!("pool:{}", pool_id)) {
+            auction.token_address = token.address;
+            auction.paired_token = token.paired_token;
+        }
+
+        auctions.auctions.push(auction);
+    }
+
+    Ok(auctions)
+}
+
+/// Round of an auction event that carries no round of its own
+///
+/// Uses the round won in the same transaction, else the pool's latest round.
+fn auction_round(events: &ClankerEvents, rounds: &StoreGetInt64, pool_id: &str, tx_hash: &str) -> Option<u64> {
+    events
+        .auction_won
+        .iter()
+        .find(|won| won.pool_id == pool_id && won.tx_hash == tx_hash)
+        .map(|won| won.round)
+        .or_else(|| rounds.get_last(&format!("round:{}", pool_id)).map(|round| round as u64))
+}
+
+/// Store total auction payments per winner
+#[substreams::handlers::store]
+pub fn store_auction_winner_payments(events: ClankerEvents, store: StoreAddBigInt) {
+    for auction in &events.auction_won {
+        if let Ok(amount) = BigInt::from_str(&auction.payment_amount) {
+            store.add(0, &format!("paid:{}", auction.winner), amount);
+        }
+    }
+}
+
+/// Store auctions won per winner
+#[substreams::handlers::store]
+pub fn store_auction_winner_counts(events: ClankerEvents, store: StoreAddInt64) {
+    for auction in &events.auction_won {
+        store.add(0, &format!("wins:{}", auction.winner), 1);
+    }
+}
+
+/// Store auction revenue per pool and per token
+#[substreams::handlers::store]
+pub fn store_auction_revenue(events: ClankerEvents, store: StoreAddBigInt) {
+    for auction in &events.auction_won {
+        if let Ok(amount) = BigInt::from_str(&auction.payment_amount) {
+            store.add(0, &format!("revenue:pool:{}", auction.pool_id), amount.clone());
+            if !auction.token_address.is_empty() {
+                store.add(0, &format!("revenue:token:{}", auction.token_address), amount);
+            }
+        }
+    }
+}
+
+// ============================================================================
+// Token Metadata
+// ============================================================================
+
+/// Social platforms recognized in token metadata
+#[derive(Clone, Copy)]
+enum SocialPlatform {
+    Website,
+    X,
+    Telegram,
+    Farcaster,
+    Discord,
+    Github,
+}
+
+/// Parse the token metadata JSON into structured social fields
+///
+/// Understands Clanker's `description` / `socialMediaUrls` / `auditUrls`
+/// layout as well as top-level platform keys. Anything else is kept as JSON
+/// in `unrecognized`, and invalid JSON is reported in `parse_error`.
+fn parse_socials(metadata: &str) -> TokenSocials {
+    let mut socials = TokenSocials::default();
+    if metadata.trim().is_empty() {
+        return socials;
+    }
+
+    let fields = match serde_json::from_str::<serde_json::Value>(metadata) {
+        Ok(serde_json::Value::Object(fields)) => fields,
+        Ok(_) => {
+            socials.parse_error = "metadata is not a JSON object".to_string();
+            return socials;
+        }
+        Err(err) => {
+            socials.parse_error = err.to_string();
+            return socials;
+        }
+    };
+
+    let mut unrecognized = serde_json::Map::new();
+    let mut unrecognized_socials = Vec::new();
+
+    for (key, value) in fields {
+        match key.as_str() {
+            "description" => socials.description = json_string(&value),
+            "auditUrls" | "audit_urls" | "audits" => {
+                socials.audit_urls = json_strings(&value);
+            }
+            "socialMediaUrls" | "social_media_urls" | "socials" => {
+                // Keep anything that isn't a list as is rather than dropping it
+                let entries = match value {
+                    serde_json::Value::Array(entries) => entries,
+                    serde_json::Value::Null => continue,
+                    other => {
+                        unrecognized.insert(key, other);
+                        continue;
+                    }
+                };
+                for entry in entries {
+                    let (platform, url) = match &entry {
+                        serde_json::Value::Object(social) => (
+                            social.get("platform").map(json_string).unwrap_or_default(),
+                            social.get("url").map(json_string).unwrap_or_default(),
+                        ),
+                        _ => (String::new(), json_string(&entry)),
+                    };
+                    let platform = social_platform(&platform).or_else(|| url_platform(&url));
+                    match platform {
+                        Some(platform) if !url.is_empty() => set_social(&mut socials, platform, url),
+                        _ => unrecognized_socials.push(entry),
+                    }
+                }
+            }
+            _ => match social_platform(&key) {
+                Some(platform) => set_social(&mut socials, platform, json_string(&value)),
+                None => {
+                    unrecognized.insert(key, value);
+                }
+            },
+        }
+    }
+
+    if !unrecognized_socials.is_empty() {
+        unrecognized.insert(
+            "socialMediaUrls".to_string(),
+            serde_json::Value::Array(unrecognized_socials),
+        );
+    }
+    if !unrecognized.is_empty() {
+        socials.unrecognized = serde_json::Value::Object(unrecognized).to_string();
+    }
+
+    socials
+}
+
+/// Record a social link, keeping the first one seen per platform
+fn set_social(socials: &mut TokenSocials, platform: SocialPlatform, url: String) {
+    let url = url.trim().to_string();
+    let slot = match platform {
+        SocialPlatform::Website => &mut socials.website,
+        SocialPlatform::X => &mut socials.x,
+        SocialPlatform::Telegram => &mut socials.telegram,
+        SocialPlatform::Farcaster => &mut socials.farcaster,
+        SocialPlatform::Discord => &mut socials.discord,
+        SocialPlatform::Github => &mut socials.github,
+    };
+    if !slot.is_empty() || url.is_empty() {
+        return;
+    }
+
+    let handle = matches!(platform, SocialPlatform::X).then(|| x_handle(&url));
+    *slot = url;
+    if let Some(handle) = handle {
+        socials.x_handle = handle;
+    }
+}
+
+/// Platform named by a metadata key or a `platform` field
+fn social_platform(name: &str) -> Option<SocialPlatform> {
+    match name.trim().to_lowercase().as_str() {
+        "website" | "web" | "site" | "homepage" | "url" => Some(SocialPlatform::Website),
+        "x" | "twitter" => Some(SocialPlatform::X),
+        "telegram" | "tg" => Some(SocialPlatform::Telegram),
+        "farcaster" | "warpcast" => Some(SocialPlatform::Farcaster),
+        "discord" => Some(SocialPlatform::Discord),
+        "github" => Some(SocialPlatform::Github),
+        _ => None,
+    }
+}
+
+/// Platform of a social link, from its host
+fn url_platform(url: &str) -> Option<SocialPlatform> {
+    let host = url_host(url);
+    match host.as_str() {
+        "" => None,
+        "x.com" | "twitter.com" => Some(SocialPlatform::X),
+        "t.me" | "telegram.me" | "telegram.org" => Some(SocialPlatform::Telegram),
+        "warpcast.com" | "farcaster.xyz" => Some(SocialPlatform::Farcaster),
+        "discord.gg" | "discord.com" => Some(SocialPlatform::Discord),
+        "github.com" => Some(SocialPlatform::Github),
+        _ if url.contains("://") => Some(SocialPlatform::Website),
+        _ => None,
+    }
+}
+
+/// Lowercase host of a URL without scheme, `www.` or port
+fn url_host(url: &str) -> String {
+    let rest = url.trim().split_once("://").map(|(_, rest)| rest).unwrap_or(url.trim());
+    let host = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    let host = host.split(':').next().unwrap_or_default().to_lowercase();
+    host.trim_start_matches("www.").to_string()
+}
+
+/// Lowercase X handle (without `@`) from an X/Twitter URL or a bare handle
+///
+/// Anything that is not a valid handle (1-15 of `[a-z0-9_]`), or is a reserved
+/// page like `/i/...` or `/intent/...`, gives an empty string.
+fn x_handle(value: &str) -> String {
+    let value = value.trim();
+    let handle = match value.split_once("://") {
+        Some((_, rest)) => rest.split('/').nth(1).unwrap_or_default(),
+        None if value.contains('/') => value.split('/').nth(1).unwrap_or_default(),
+        None => value,
+    };
+    let handle = handle.split(['?', '#']).next().unwrap_or_default();
+    let handle = handle.trim_start_matches('@').to_lowercase();
+    let valid = (1..=MAX_X_HANDLE_LEN).contains(&handle.len())
+        && handle.bytes().all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'_')
+        && !RESERVED_X_PATHS.contains(&handle.as_str());
+    if valid {
+        handle
+    } else {
+        String::new()
+    }
+}
+
+/// JSON value as a string (strings unquoted, other values as JSON)
+fn json_string(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(s) => s.trim().to_string(),
+        serde_json::Value::Null => String::new(),
+        other => other.to_string(),
+    }
+}
+
+/// JSON string or array of strings as a list of non-empty strings
+fn json_strings(value: &serde_json::Value) -> Vec<String> {
+    match value {
+        serde_json::Value::Array(values) => values.iter().map(json_string).filter(|s| !s.is_empty()).collect(),
+        other => Some(json_string(other)).filter(|s| !s.is_empty()).into_iter().collect(),
+    }
+}
+
+/// Parse the token context JSON into interface, platform and social-origin fields
+///
+/// `origin` buckets the launch as "bankr", "sdk", "farcaster", "clanker_world",
+/// "other", or "contract" when no interface is given (a raw contract call).
+/// Farcaster launches also get the requester's `fid` and the `cast_hash`.
+fn parse_context(context: &str) -> TokenContext {
+    let mut parsed = TokenContext::default();
+
+    if !context.trim().is_empty() {
+        match serde_json::from_str::<serde_json::Value>(context) {
+            Ok(serde_json::Value::Object(fields)) => {
+                let field = |names: &[&str]| {
+                    names
+                        .iter()
+                        .find_map(|name| fields.get(*name))
+                        .map(json_string)
+                        .unwrap_or_default()
+                };
+                parsed.interface = field(&["interface"]);
+                parsed.platform = field(&["platform"]);
+                parsed.message_id = field(&["messageId", "message_id"]);
+                parsed.user_id = field(&["id", "userId", "user_id"]);
+            }
+            Ok(_) => parsed.parse_error = "context is not a JSON object".to_string(),
+            Err(err) => parsed.parse_error = err.to_string(),
+        }
+    }
+
+    let interface = parsed.interface.to_lowercase();
+    let platform = parsed.platform.to_lowercase();
+    parsed.origin = if interface.is_empty() {
+        "contract"
+    } else if interface.contains("bankr") {
+        "bankr"
+    } else if interface.contains("sdk") {
+        "sdk"
+    } else if platform == "farcaster" {
+        "farcaster"
+    } else if interface.contains("clanker.world") || interface.contains("clanker world") {
+        "clanker_world"
+    } else {
+        "other"
+    }
+    .to_string();
+
+    // Farcaster launches carry the requester's FID and the cast that asked for the token.
+    // Both come from the deployer, so FIDs must fit a BIGINT and cast hashes must be
+    // 0x-prefixed 20-byte hex.
+    if platform == "farcaster" {
+        parsed.fid = parsed
+            .user_id
+            .parse::<u64>()
+            .ok()
+            .filter(|fid| *fid <= i64::MAX as u64)
+            .unwrap_or_default();
+        let cast_hash = parsed.message_id.to_lowercase();
+        let is_cast_hash = cast_hash.len() == CAST_HASH_LEN
+            && cast_hash.starts_with("0x")
+            && cast_hash[2..].bytes().all(|b| b.is_ascii_hexdigit());
+        if is_cast_hash {
+            parsed.cast_hash = cast_hash;
+        }
+    }
+
+    parsed
+}
+
+/// Set the structured social columns of a tokens row
+fn set_socials(row: &mut Row, socials: &TokenSocials) {
+    row.set("description", &socials.description)
+        .set("website", &socials.website)
+        .set("x_url", &socials.x)
+        .set("x_handle", &socials.x_handle)
+        .set("telegram", &socials.telegram)
+        .set("farcaster", &socials.farcaster)
+        .set("discord", &socials.discord)
+        .set("github", &socials.github)
+        .set("audit_urls", socials.audit_urls.join(","))
+        .set("metadata_extra", &socials.unrecognized)
+        .set("metadata_parse_error", &socials.parse_error);
+}
+
+/// Normalize a token symbol or name for lookalike matching
+///
+/// Case-folds, maps common confusables (Cyrillic and Greek homoglyphs,
+/// fullwidth forms, 0 for o) to ASCII and drops everything that is not
+/// a letter or digit, so "$PEPE", "Реpe" and "ＰＥＰＥ" all become "pepe".
+/// l, 1, | and the i homoglyphs all fold to 'i', so "1NCH" matches "INCH".
+fn normalize_name(value: &str) -> String {
+    value
+        .chars()
+        .map(|c| match c {
+            // Fullwidth ASCII variants
+            '\u{FF01}'..='\u{FF5E}' => char::from_u32(c as u32 - 0xFEE0).unwrap_or(c),
+            _ => c,
+        })
+        .flat_map(char::to_lowercase)
+        .map(|c| match c {
+            'а' | 'α' => 'a',
+            'в' | 'β' => 'b',
+            'с' | 'ϲ' => 'c',
+            'ԁ' => 'd',
+            'е' | 'ё' | 'ε' => 'e',
+            'ɡ' => 'g',
+            'һ' | 'н' => 'h',
+            'l' | 'і' | 'ι' | 'ı' | 'ӏ' | '1' | '|' => 'i',
+            'ј' => 'j',
+            'к' | 'κ' => 'k',
+            'м' | 'μ' => 'm',
+            'п' | 'η' => 'n',
+            'о' | 'ο' | 'σ' | '0' => 'o',
+            'р' | 'ρ' => 'p',
+            'ѕ' => 's',
+            'т' | 'τ' => 't',
+            'υ' => 'u',
+            'ν' => 'v',
+            'ш' | 'ω' => 'w',
+            'х' | 'χ' => 'x',
+            'у' | 'γ' => 'y',
+            _ => c,
+        })
+        .filter(|c| c.is_alphanumeric())
+        .collect()
+}
+
+/// Normalize an image reference into a content-addressed form
+///
+/// IPFS references (`ipfs://`, gateway URLs, raw CIDs) and Arweave references
+/// (`ar://`, arweave.net URLs) resolve to their CID or transaction id and a
+/// canonical gateway URL; other http(s) URLs are kept as they are.
+fn normalize_image(image: &str) -> ImageRef {
+    let image = image.trim();
+    let lower = image.to_lowercase();
+    let mut image_ref = ImageRef::default();
+
+    if image.is_empty() {
+        return image_ref;
+    }
+
+    if let Some(rest) = strip_prefix_ignore_case(image, "ipfs://") {
+        let rest = strip_prefix_ignore_case(rest, "ipfs/").unwrap_or(rest);
+        return ipfs_image(rest);
+    }
+    if let Some(rest) = strip_prefix_ignore_case(image, "ar://") {
+        return arweave_image(rest);
+    }
+    if is_ipfs_cid(image.split(['/', '?', '#']).next().unwrap_or_default()) {
+        return ipfs_image(image);
+    }
+
+    if lower.starts_with("https://") || lower.starts_with("http://") {
+        let (_, rest) = image.split_once("://").unwrap_or_default();
+        let (host, path) = rest.split_once('/').unwrap_or((rest, ""));
+        let host = host.to_lowercase();
+
+        // Path gateways (https://gateway/ipfs/<cid>/...)
+        if let Some(index) = path.find("ipfs/") {
+            let candidate = &path[index + "ipfs/".len()..];
+            if is_ipfs_cid(candidate.split(['/', '?', '#']).next().unwrap_or_default()) {
+                return ipfs_image(candidate);
+            }
+        }
+        // Subdomain gateways (https://<cid>.ipfs.dweb.link/...)
+        if let Some((cid, _)) = host.split_once(".ipfs.") {
+            if is_ipfs_cid(cid) {
+                return ipfs_image(&format!("{}/{}", cid, path));
+            }
+        }
+        if host == "arweave.net" || host.ends_with(".arweave.net") {
+            return arweave_image(path);
+        }
+
+        image_ref.scheme = if lower.starts_with("https://") { "https" } else { "http" }.to_string();
+        image_ref.url = image.to_string();
+        return image_ref;
+    }
+
+    if lower.starts_with("data:") {
+        image_ref.scheme = "data".to_string();
+        return image_ref;
+    }
+
+    image_ref.scheme = "unknown".to_string();
+    image_ref
+}
+
+/// IPFS image from `<cid>[/path]`
+fn ipfs_image(reference: &str) -> ImageRef {
+    let reference = reference.split(['?', '#']).next().unwrap_or_default().trim_end_matches('/');
+    let (cid, path) = reference.split_once('/').unwrap_or((reference, ""));
+    let path = if path.is_empty() { String::new() } else { format!("/{}", path) };
+
+    ImageRef {
+        scheme: "ipfs".to_string(),
+        cid: cid.to_string(),
+        url: format!("https://ipfs.io/ipfs/{}{}", cid, path),
+    }
+}
+
+/// Arweave image from `<transaction id>[/path]`
+fn arweave_image(reference: &str) -> ImageRef {
+    let reference = reference.split(['?', '#']).next().unwrap_or_default().trim_matches('/');
+    let id = reference.split('/').next().unwrap_or_default();
+
+    ImageRef {
+        scheme: "arweave".to_string(),
+        cid: id.to_string(),
+        url: format!("https://arweave.net/{}", reference),
+    }
+}
+
+/// Check whether a string looks like an IPFS CID (v0 base58 or v1 base32)
+fn is_ipfs_cid(value: &str) -> bool {
+    let is_v0 = value.len() == 46
+        && value.starts_with("Qm")
+        && value.chars().all(|c| c.is_ascii_alphanumeric() && !"0OIl".contains(c));
+    let is_v1 = value.len() >= 50
+        && value.starts_with('b')
+        && value.chars().all(|c| c.is_ascii_lowercase() || ('2'..='7').contains(&c));
+    is_v0 || is_v1
+}
+
+/// Strip an ASCII prefix regardless of case
+fn strip_prefix_ignore_case<'a>(value: &'a str, prefix: &str) -> Option<&'a str> {
+    value
+        .get(..prefix.len())
+        .filter(|head| head.eq_ignore_ascii_case(prefix))
+        .map(|_| &value[prefix.len()..])
+}
+
+/// Set the normalized image columns of a row
+fn set_image_ref(row: &mut Row, image: &ImageRef) {
+    row.set("image_scheme", &image.scheme)
+        .set("image_cid", &image.cid)
+        .set("image_url", &image.url);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ADDRESS_A: &str = "0x1111111111111111111111111111111111111111";
+    const ADDRESS_B: &str = "2222222222222222222222222222222222222222";
+
+    #[test]
+    fn parse_contract_addresses_reads_lists() {
+        let params = format!(
+            "clanker_factory={}, clanker_airdrop = {}|{} ",
+            ADDRESS_B, ADDRESS_A, ADDRESS_B
+        );
+        assert_eq!(
+            parse_contract_addresses(&params, "clanker_airdrop", &[DEFAULT_CLANKER_AIRDROP]),
+            vec![vec![0x11; 20], vec![0x22; 20]]
+        );
+
+        // Repeated names add up
+        let params = format!(
+            "clanker_airdrop={},clanker_airdrop={}",
+            ADDRESS_A, ADDRESS_B
+        );
+        assert_eq!(
+            parse_contract_addresses(&params, "clanker_airdrop", &[]),
+            vec![vec![0x11; 20], vec![0x22; 20]]
+        );
+    }
+
+    #[test]
+    fn parse_contract_addresses_skips_malformed_entries() {
+        let defaults = vec![DEFAULT_CLANKER_AIRDROP.to_vec()];
+        assert_eq!(
+            parse_contract_addresses("", "clanker_airdrop", &[DEFAULT_CLANKER_AIRDROP]),
+            defaults
+        );
+
+        let params = "clanker_airdrop=0x1234,clanker_airdrop=0xzz11111111111111111111111111111111111111,clanker_airdrop";
+        assert_eq!(
+            parse_contract_addresses(params, "clanker_airdrop", &[DEFAULT_CLANKER_AIRDROP]),
+            defaults
+        );
+
+        let params = format!("clanker_airdrop=0x1234|{}|", ADDRESS_A);
+        assert_eq!(
+            parse_contract_addresses(&params, "clanker_airdrop", &[DEFAULT_CLANKER_AIRDROP]),
+            vec![vec![0x11; 20]]
+        );
+    }
+
+    fn airdrop(supply: &str, lockup_duration: u64, vesting_duration: u64) -> AirdropCreated {
+        AirdropCreated {
+            block_timestamp: 1_000,
+            supply: supply.to_string(),
+            lockup_duration,
+            vesting_duration,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn airdrop_unlocks_round_down_and_settle_on_the_last_day() {
+        // 1000 tokens over 2.5 days: 400, 400, then the 200 remainder
+        let unlocks = airdrop_unlocks(&airdrop("1000", 500, SECONDS_PER_DAY * 5 / 2));
+        let amounts: Vec<&str> = unlocks.iter().map(|u| u.amount.as_str()).collect();
+        assert_eq!(amounts, vec!["400", "400", "200"]);
+        assert_eq!(unlocks[0].unlock_start, 1_500);
+        assert_eq!(unlocks[2].unlock_end, 1_500 + SECONDS_PER_DAY * 5 / 2);
+        assert_eq!(unlocks[2].cumulative_amount, "1000");
+
+        // 10 tokens over 3 days round down to 3, 3, then the 4 remainder
+        let unlocks = airdrop_unlocks(&airdrop("10", 0, SECONDS_PER_DAY * 3));
+        let amounts: Vec<&str> = unlocks.iter().map(|u| u.amount.as_str()).collect();
+        assert_eq!(amounts, vec!["3", "3", "4"]);
+    }
+
+    #[test]
+    fn airdrop_unlocks_without_vesting_unlock_at_lockup_end() {
+        let unlocks = airdrop_unlocks(&airdrop("1000", 86_400, 0));
+        assert_eq!(unlocks.len(), 1);
+        assert_eq!(unlocks[0].amount, "1000");
+        assert_eq!(unlocks[0].unlock_start, 87_400);
+        assert_eq!(unlocks[0].unlock_end, 87_400);
+
+        assert!(airdrop_unlocks(&airdrop("not a number", 0, 0)).is_empty());
+    }
+
+    #[test]
+    fn airdrop_unlocks_cap_the_schedule_length() {
+        let unlocks = airdrop_unlocks(&airdrop(
+            "1000",
+            0,
+            SECONDS_PER_DAY * (MAX_UNLOCK_DAYS + 10),
+        ));
+        assert_eq!(unlocks.len() as u64, MAX_UNLOCK_DAYS);
+        assert_eq!(unlocks.last().unwrap().cumulative_amount, "1000");
+    }
+
+    #[test]
+    fn transfer_type_classifies_mints_and_burns() {
+        let zero = [0u8; 20];
+        let wallet = [0x11u8; 20];
+        let dead = Hex::decode(DEAD_ADDRESS).unwrap();
+
+        assert_eq!(transfer_type(&zero, &wallet), "mint");
+        assert_eq!(transfer_type(&wallet, &zero), "burn");
+        assert_eq!(transfer_type(&wallet, &dead), "dead_burn");
+        assert_eq!(transfer_type(&wallet, &[0x22u8; 20]), "transfer");
+    }
+
+    fn as_f64(value: Option<BigDecimal>) -> f64 {
+        value.unwrap().to_string().parse().unwrap()
+    }
+
+    #[test]
+    fn sqrt_price_to_price_follows_token_side() {
+        let q96 = BigInt::from_str("79228162514264337593543950336").unwrap();
+        assert_eq!(as_f64(sqrt_price_to_price(&q96, true)), 1.0);
+        assert_eq!(as_f64(sqrt_price_to_price(&q96, false)), 1.0);
+
+        // sqrtPriceX96 = 2^97 means 4 currency1 per currency0
+        let doubled = q96.clone() * BigInt::from(2);
+        assert_eq!(as_f64(sqrt_price_to_price(&doubled, true)), 4.0);
+        assert_eq!(as_f64(sqrt_price_to_price(&doubled, false)), 0.25);
+
+        assert!(sqrt_price_to_price(&BigInt::zero(), true).is_none());
+    }
+
+    #[test]
+    fn tick_to_price_matches_swap_price_on_either_side() {
+        assert_eq!(as_f64(tick_to_price(0)), 1.0);
+
+        // Launch at 0.1 paired token per token
+        let launch = as_f64(tick_to_price(-23_028));
+        assert!((launch - 0.09999).abs() < 1e-5);
+
+        // Same price as a pool with the token as currency0 (0.1 paired per token)
+        // and as currency1 (10 tokens per paired token)
+        let as_currency0 = BigInt::from_str("25052894984021797146183221488").unwrap();
+        let as_currency1 = BigInt::from_str("250553947533412109193337304114").unwrap();
+        assert!((as_f64(sqrt_price_to_price(&as_currency0, true)) - launch).abs() < 1e-12);
+        assert!((as_f64(sqrt_price_to_price(&as_currency1, false)) - launch).abs() < 1e-12);
+    }
+
+    #[test]
+    fn parse_socials_reports_invalid_metadata() {
+        assert_eq!(parse_socials("  "), TokenSocials::default());
+        assert_eq!(
+            parse_socials("[1, 2]").parse_error,
+            "metadata is not a JSON object"
+        );
+        assert_eq!(
+            parse_socials("\"pepe\"").parse_error,
+            "metadata is not a JSON object"
+        );
+        assert!(!parse_socials("{not json").parse_error.is_empty());
+    }
+
+    #[test]
+    fn parse_socials_reads_social_media_urls() {
+        let socials = parse_socials(
+            r#"{
+                "description": "A frog",
+                "socialMediaUrls": [
+                    {"platform": "x", "url": "https://x.com/PepeCoin?s=20"},
+                    {"platform": "twitter", "url": "https://x.com/second"},
+                    {"platform": "telegram", "url": "https://t.me/pepe"},
+                    "https://warpcast.com/pepe",
+                    {"platform": "myspace", "url": "myspace/pepe"}
+                ],
+                "auditUrls": ["https://audits.example/pepe", ""],
+                "foo": 1
+            }"#,
+        );
+
+        assert_eq!(socials.description, "A frog");
+        assert_eq!(socials.x, "https://x.com/PepeCoin?s=20");
+        assert_eq!(socials.x_handle, "pepecoin");
+        assert_eq!(socials.telegram, "https://t.me/pepe");
+        assert_eq!(socials.farcaster, "https://warpcast.com/pepe");
+        assert!(socials.website.is_empty());
+        assert_eq!(
+            socials.audit_urls,
+            vec!["https://audits.example/pepe".to_string()]
+        );
+        assert!(socials.parse_error.is_empty());
+
+        let unrecognized: serde_json::Value = serde_json::from_str(&socials.unrecognized).unwrap();
+        assert_eq!(
+            unrecognized,
+            serde_json::json!({
+                "foo": 1,
+                "socialMediaUrls": [{"platform": "myspace", "url": "myspace/pepe"}],
+            })
+        );
+    }
+
+    #[test]
+    fn parse_socials_reads_top_level_platform_keys() {
+        let socials = parse_socials(
+            r#"{"website": "https://pepe.xyz", "twitter": "@Pepe", "discord": "https://discord.gg/pepe"}"#,
+        );
+
+        assert_eq!(socials.website, "https://pepe.xyz");
+        assert_eq!(socials.x, "@Pepe");
+        assert_eq!(socials.x_handle, "pepe");
+        assert_eq!(socials.discord, "https://discord.gg/pepe");
+        assert!(socials.unrecognized.is_empty());
+    }
+
+    #[test]
+    fn parse_socials_keeps_socials_that_are_not_lists() {
+        let socials =
+            parse_socials(r#"{"socials": "https://x.com/pepe", "social_media_urls": null}"#);
+
+        assert!(socials.x.is_empty());
+        assert_eq!(socials.unrecognized, r#"{"socials":"https://x.com/pepe"}"#);
+    }
+
+    #[test]
+    fn parse_context_reports_invalid_context() {
+        let context = parse_context("");
+        assert_eq!(context.origin, "contract");
+        assert!(context.parse_error.is_empty());
+
+        let context = parse_context("[1, 2]");
+        assert_eq!(context.origin, "contract");
+        assert_eq!(context.parse_error, "context is not a JSON object");
+
+        assert!(!parse_context("{\"interface\":").parse_error.is_empty());
+    }
+
+    #[test]
+    fn parse_context_buckets_origin() {
+        let origin = |context: &str| parse_context(context).origin;
+        assert_eq!(
+            origin(r#"{"interface": "Bankr", "platform": "farcaster"}"#),
+            "bankr"
+        );
+        assert_eq!(origin(r#"{"interface": "Clanker SDK"}"#), "sdk");
+        assert_eq!(
+            origin(r#"{"interface": "clanker.world", "platform": "Farcaster"}"#),
+            "farcaster"
+        );
+        assert_eq!(origin(r#"{"interface": "clanker.world"}"#), "clanker_world");
+        assert_eq!(origin(r#"{"interface": "Some App"}"#), "other");
+        assert_eq!(origin(r#"{"platform": "farcaster"}"#), "contract");
+    }
+
+    #[test]
+    fn parse_context_reads_farcaster_fid_and_cast() {
+        let context = parse_context(
+            r#"{"interface": "clanker", "platform": "farcaster", "id": 1234,
+                "messageId": "0xABCDEF0123456789abcdef0123456789ABCDEF01"}"#,
+        );
+        assert_eq!(context.fid, 1234);
+        assert_eq!(
+            context.cast_hash,
+            "0xabcdef0123456789abcdef0123456789abcdef01"
+        );
+
+        // Only Farcaster launches carry a FID and cast
+        let context = parse_context(r#"{"interface": "clanker", "platform": "web", "id": "1234"}"#);
+        assert_eq!(context.fid, 0);
+        assert_eq!(context.user_id, "1234");
+    }
+
+    #[test]
+    fn parse_context_rejects_out_of_range_fid_and_malformed_cast() {
+        let context = parse_context(&format!(
+            r#"{{"interface": "clanker", "platform": "farcaster", "id": "18446744073709551615", "messageId": "0x{}"}}"#,
+            "a".repeat(98)
+        ));
+        assert_eq!(context.fid, 0);
+        assert!(context.cast_hash.is_empty());
+
+        let context = parse_context(
+            r#"{"interface": "clanker", "platform": "farcaster", "id": "9223372036854775807", "messageId": "cast-1"}"#,
+        );
+        assert_eq!(context.fid, i64::MAX as u64);
+        assert!(context.cast_hash.is_empty());
+    }
+
+    const CID_V0: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+    const CID_V1: &str = "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi";
+    const ARWEAVE_ID: &str = "bNLcWjC3ybrq7Dm0V_bQq8Uj0ad-4xyPzRxCB5L2uMo";
+
+    fn image(scheme: &str, cid: &str, url: &str) -> ImageRef {
+        ImageRef {
+            scheme: scheme.to_string(),
+            cid: cid.to_string(),
+            url: url.to_string(),
+        }
+    }
+
+    #[test]
+    fn normalize_image_resolves_ipfs_references() {
+        let canonical = image("ipfs", CID_V0, &format!("https://ipfs.io/ipfs/{}", CID_V0));
+        assert_eq!(normalize_image(&format!("ipfs://{}", CID_V0)), canonical);
+        assert_eq!(
+            normalize_image(&format!("IPFS://ipfs/{}/", CID_V0)),
+            canonical
+        );
+        assert_eq!(normalize_image(CID_V0), canonical);
+        assert_eq!(
+            normalize_image(&format!(
+                "https://gateway.pinata.cloud/ipfs/{}?filename=pepe.png",
+                CID_V0
+            )),
+            canonical
+        );
+
+        assert_eq!(
+            normalize_image(&format!("https://{}.ipfs.dweb.link/logo.png", CID_V1)),
+            image(
+                "ipfs",
+                CID_V1,
+                &format!("https://ipfs.io/ipfs/{}/logo.png", CID_V1)
+            )
+        );
+        assert_eq!(
+            normalize_image(&format!("ipfs://{}/logo.png", CID_V1)),
+            image(
+                "ipfs",
+                CID_V1,
+                &format!("https://ipfs.io/ipfs/{}/logo.png", CID_V1)
+            )
+        );
+    }
+
+    #[test]
+    fn normalize_image_resolves_arweave_references() {
+        let canonical = image(
+            "arweave",
+            ARWEAVE_ID,
+            &format!("https://arweave.net/{}", ARWEAVE_ID),
+        );
+        assert_eq!(normalize_image(&format!("ar://{}", ARWEAVE_ID)), canonical);
+        assert_eq!(
+            normalize_image(&format!("https://arweave.net/{}", ARWEAVE_ID)),
+            canonical
+        );
+    }
+
+    #[test]
+    fn normalize_image_keeps_other_references() {
+        assert_eq!(normalize_image("  "), ImageRef::default());
+        assert_eq!(
+            normalize_image("https://example.com/pepe.png"),
+            image("https", "", "https://example.com/pepe.png")
+        );
+        assert_eq!(
+            normalize_image("data:image/png;base64,AAAA"),
+            image("data", "", "")
+        );
+        assert_eq!(normalize_image("pepe.png"), image("unknown", "", ""));
+    }
+
+    #[test]
+    fn is_ipfs_cid_checks_v0_and_v1() {
+        assert!(is_ipfs_cid(CID_V0));
+        assert!(is_ipfs_cid(CID_V1));
+        assert!(!is_ipfs_cid(&CID_V0.replace('Y', "0")));
+        assert!(!is_ipfs_cid(&CID_V1.to_uppercase()));
+        assert!(!is_ipfs_cid(&CID_V1[..20]));
+        assert!(!is_ipfs_cid(""));
+    }
+
+    #[test]
+    fn normalize_name_folds_lookalikes() {
+        assert_eq!(normalize_name("$PEPE"), "pepe");
+        // Cyrillic Р and е, fullwidth Latin, Greek Ρ and Ε
+        assert_eq!(normalize_name("\u{0420}\u{0435}pe"), "pepe");
+        assert_eq!(normalize_name("ＰＥＰＥ"), "pepe");
+        assert_eq!(normalize_name("\u{03A1}\u{0395}\u{03A1}\u{0395}"), "pepe");
+        assert_eq!(normalize_name("B0NK"), "bonk");
+        assert_eq!(normalize_name("Pepe Coin!"), "pepecoin");
+        assert_eq!(normalize_name("$$$"), "");
+        // Cyrillic І, and the i/l/1 family
+        assert_eq!(normalize_name("P\u{0406}XEL"), normalize_name("PIXEL"));
+        assert_eq!(normalize_name("1NCH"), normalize_name("INCH"));
+        assert_eq!(normalize_name("|NCH"), normalize_name("lnch"));
+    }
+
+    #[test]
+    fn x_handle_reads_urls_and_bare_handles() {
+        assert_eq!(
+            x_handle("https://twitter.com/Clanker_World/status/1"),
+            "clanker_world"
+        );
+        assert_eq!(x_handle("x.com/pepe"), "pepe");
+        assert_eq!(x_handle(" @Pepe "), "pepe");
+    }
+
+    #[test]
+    fn x_handle_rejects_invalid_handles() {
+        assert_eq!(x_handle(&"x".repeat(80)), "");
+        assert_eq!(x_handle("https://x.com/pe-pe"), "");
+        assert_eq!(x_handle("https://x.com/"), "");
+        assert_eq!(x_handle("https://x.com/ｐｅｐｅ"), "");
+        assert_eq!(x_handle("https://x.com/i/communities/1868"), "");
+        assert_eq!(x_handle("https://x.com/intent/follow?screen_name=pepe"), "");
+        assert_eq!(x_handle("https://twitter.com/home"), "");
+        assert_eq!(x_handle("https://x.com/search?q=pepe"), "");
+    }
+}